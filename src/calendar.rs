@@ -0,0 +1,249 @@
+use std::fmt;
+
+use jiff::civil::{Date, Weekday};
+
+use crate::Error;
+
+/// Calendar-relative representation of a point in time, such as "yesterday", "last Tuesday"
+/// or "next month"
+///
+/// Unlike `HumanTime`, which describes the time elapsed, the text tells how many calendar
+/// days, weeks, months or years lie between the dates, as seen in the time zone of the
+/// reference. Weeks start on Monday.
+///
+/// | Dates                          | Text                                 |
+/// |--------------------------------|--------------------------------------|
+/// | same day                       | "today"                              |
+/// | one day apart                  | "tomorrow", "yesterday"              |
+/// | up to six days apart           | "next Friday", "last Tuesday"        |
+/// | in adjacent weeks              | "next week", "last week"             |
+/// | in the same month              | "in 2 weeks", "2 weeks ago"          |
+/// | in adjacent months             | "next month", "last month"           |
+/// | in the same year               | "in 3 months", "3 months ago"        |
+/// | in adjacent years              | "next year", "last year"             |
+/// | further apart                  | "in 5 years", "5 years ago"          |
+/// | a century or more apart        | "in the 1800s", "in the 2200s"       |
+///
+/// ```
+/// use jiff::civil::date;
+/// use jiffy::CalendarTime;
+///
+/// let now = date(2026, 10, 18).at(8, 0, 0, 0).in_tz("UTC").unwrap();
+/// let dt = date(2026, 10, 17).at(21, 30, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("yesterday", CalendarTime::new(&dt, &now).to_text_en().unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalendarTime {
+    date: Date,
+    reference: Date,
+}
+
+impl CalendarTime {
+    /// Create a calendar-relative representation of `dt`, relative to `reference`
+    pub fn new(dt: &jiff::Zoned, reference: &jiff::Zoned) -> Self {
+        Self {
+            date: dt.with_time_zone(reference.time_zone().clone()).date(),
+            reference: reference.date(),
+        }
+    }
+
+    /// Number of calendar days from the reference date
+    fn days(&self) -> Result<i32, Error> {
+        Ok(self.date.since(self.reference)?.get_days())
+    }
+
+    /// Gives English text representation
+    pub fn to_text_en(&self) -> Result<String, Error> {
+        match self.days()? {
+            0 => return Ok("today".to_string()),
+            1 => return Ok("tomorrow".to_string()),
+            -1 => return Ok("yesterday".to_string()),
+            2..=6 => return Ok(format!("next {}", weekday_name_en(self.date.weekday()))),
+            -6..=-2 => return Ok(format!("last {}", weekday_name_en(self.date.weekday()))),
+            _ => {}
+        }
+
+        let weeks = start_of_week(self.date)?
+            .since(start_of_week(self.reference)?)?
+            .get_days()
+            / 7;
+        let months = (i32::from(self.date.year()) * 12 + i32::from(self.date.month()))
+            - (i32::from(self.reference.year()) * 12 + i32::from(self.reference.month()));
+        let years = i32::from(self.date.year()) - i32::from(self.reference.year());
+
+        let text = if weeks.abs() == 1 {
+            adjacent_en("week", weeks)
+        } else if months == 0 {
+            count_en("week", weeks)
+        } else if months.abs() == 1 {
+            adjacent_en("month", months)
+        } else if years == 0 {
+            count_en("month", months)
+        } else if years.abs() == 1 {
+            adjacent_en("year", years)
+        } else if years.abs() >= 100 && self.date.year() >= 100 {
+            format!("in the {}00s", self.date.year() / 100)
+        } else {
+            count_en("year", years)
+        };
+        Ok(text)
+    }
+}
+
+impl fmt::Display for CalendarTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.to_text_en().map_err(|_| fmt::Error)?;
+        f.pad(&text)
+    }
+}
+
+/// Calendar formatter in the style of moment.js, giving "Today at 3:05 PM",
+/// "Yesterday at 9:00 AM", "Last Monday at 2:30 PM" or a plain date for anything further away
+///
+/// The bucket is chosen by the number of calendar days between the dates, as seen in the
+/// time zone of the reference. Each bucket has a template in the `jiff::fmt::strtime` format,
+/// rendered with the time in the time zone of the reference.
+///
+/// | Bucket       | Days     | Default template            | Example                  |
+/// |--------------|----------|-----------------------------|--------------------------|
+/// | `same_day`   | 0        | `Today at %-I:%M %p`        | "Today at 3:05 PM"       |
+/// | `next_day`   | 1        | `Tomorrow at %-I:%M %p`     | "Tomorrow at 3:05 PM"    |
+/// | `next_week`  | 2 to 6   | `%A at %-I:%M %p`           | "Friday at 3:05 PM"      |
+/// | `last_day`   | -1       | `Yesterday at %-I:%M %p`    | "Yesterday at 3:05 PM"   |
+/// | `last_week`  | -6 to -2 | `Last %A at %-I:%M %p`      | "Last Monday at 3:05 PM" |
+/// | `same_else`  | others   | `%m/%d/%Y`                  | "10/18/2026"             |
+///
+/// ```
+/// use jiff::civil::date;
+/// use jiffy::CalendarFormat;
+///
+/// let now = date(2026, 10, 18).at(16, 0, 0, 0).in_tz("UTC").unwrap();
+/// let dt = date(2026, 10, 17).at(9, 0, 0, 0).in_tz("UTC").unwrap();
+///
+/// let calendar = CalendarFormat::new();
+/// assert_eq!("Yesterday at 9:00 AM", calendar.format(&dt, &now).unwrap());
+///
+/// let calendar = calendar.last_day("yesterday, %H:%M");
+/// assert_eq!("yesterday, 09:00", calendar.format(&dt, &now).unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalendarFormat {
+    same_day: String,
+    next_day: String,
+    next_week: String,
+    last_day: String,
+    last_week: String,
+    same_else: String,
+}
+
+impl Default for CalendarFormat {
+    fn default() -> Self {
+        Self {
+            same_day: "Today at %-I:%M %p".to_string(),
+            next_day: "Tomorrow at %-I:%M %p".to_string(),
+            next_week: "%A at %-I:%M %p".to_string(),
+            last_day: "Yesterday at %-I:%M %p".to_string(),
+            last_week: "Last %A at %-I:%M %p".to_string(),
+            same_else: "%m/%d/%Y".to_string(),
+        }
+    }
+}
+
+impl CalendarFormat {
+    /// Create a formatter with the default templates
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the template for the day of the reference
+    #[must_use]
+    pub fn same_day(mut self, template: impl Into<String>) -> Self {
+        self.same_day = template.into();
+        self
+    }
+
+    /// Set the template for the day after the reference
+    #[must_use]
+    pub fn next_day(mut self, template: impl Into<String>) -> Self {
+        self.next_day = template.into();
+        self
+    }
+
+    /// Set the template for the 2 to 6 days after the reference
+    #[must_use]
+    pub fn next_week(mut self, template: impl Into<String>) -> Self {
+        self.next_week = template.into();
+        self
+    }
+
+    /// Set the template for the day before the reference
+    #[must_use]
+    pub fn last_day(mut self, template: impl Into<String>) -> Self {
+        self.last_day = template.into();
+        self
+    }
+
+    /// Set the template for the 2 to 6 days before the reference
+    #[must_use]
+    pub fn last_week(mut self, template: impl Into<String>) -> Self {
+        self.last_week = template.into();
+        self
+    }
+
+    /// Set the template for any other day
+    #[must_use]
+    pub fn same_else(mut self, template: impl Into<String>) -> Self {
+        self.same_else = template.into();
+        self
+    }
+
+    /// Gives text for `dt`, relative to `reference`
+    pub fn format(&self, dt: &jiff::Zoned, reference: &jiff::Zoned) -> Result<String, Error> {
+        let template = match CalendarTime::new(dt, reference).days()? {
+            0 => &self.same_day,
+            1 => &self.next_day,
+            2..=6 => &self.next_week,
+            -1 => &self.last_day,
+            -6..=-2 => &self.last_week,
+            _ => &self.same_else,
+        };
+        let dt = dt.with_time_zone(reference.time_zone().clone());
+        Ok(jiff::fmt::strtime::format(template.as_str(), &dt)?)
+    }
+}
+
+/// "next week" or "last week"
+fn adjacent_en(unit: &str, count: i32) -> String {
+    if count > 0 {
+        format!("next {}", unit)
+    } else {
+        format!("last {}", unit)
+    }
+}
+
+/// "in 2 weeks" or "2 weeks ago"
+fn count_en(unit: &str, count: i32) -> String {
+    if count > 0 {
+        format!("in {} {}s", count, unit)
+    } else {
+        format!("{} {}s ago", -count, unit)
+    }
+}
+
+/// Monday of the week `date` falls in
+fn start_of_week(date: Date) -> Result<Date, Error> {
+    let days = date.weekday().to_monday_zero_offset();
+    Ok(date.checked_sub(jiff::Span::new().days(days))?)
+}
+
+pub(crate) fn weekday_name_en(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "Monday",
+        Weekday::Tuesday => "Tuesday",
+        Weekday::Wednesday => "Wednesday",
+        Weekday::Thursday => "Thursday",
+        Weekday::Friday => "Friday",
+        Weekday::Saturday => "Saturday",
+        Weekday::Sunday => "Sunday",
+    }
+}
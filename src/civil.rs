@@ -0,0 +1,70 @@
+use std::fmt;
+
+use jiff::civil::Date;
+
+use crate::{Accuracy, Error, HumanTime, Humanize};
+
+/// Representation of a civil date relative to another one, counted in whole days, such as
+/// "today", "in 3 days" or "2 months ago"
+///
+/// ```
+/// use jiff::civil::date;
+/// use jiffy::{Accuracy, HumanDate};
+///
+/// let today = date(2026, 10, 18);
+/// let hd = HumanDate::new(date(2026, 10, 21), today);
+/// assert_eq!("in 3 days", hd.to_text_en(Accuracy::Rough).unwrap());
+///
+/// let hd = HumanDate::new(today, today);
+/// assert_eq!("today", hd.to_text_en(Accuracy::Rough).unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HumanDate {
+    date: Date,
+    reference: Date,
+}
+
+impl HumanDate {
+    /// Create `HumanDate` for `date`, relative to `reference`
+    pub fn new(date: Date, reference: Date) -> Self {
+        Self { date, reference }
+    }
+
+    /// Gives the whole days from the reference date as `HumanTime`
+    pub fn human_time(&self) -> Result<HumanTime, Error> {
+        Ok(HumanTime::from(self.date.since(self.reference)?))
+    }
+
+    /// Gives English text representation with given `accuracy`
+    pub fn to_text_en(&self, accuracy: Accuracy) -> Result<String, Error> {
+        let ht = self.human_time()?;
+        if ht.is_zero() {
+            return Ok("today".to_string());
+        }
+        ht.to_text_en(accuracy, ht.tense(accuracy)?)
+    }
+}
+
+impl fmt::Display for HumanDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accuracy = if f.alternate() {
+            Accuracy::Precise
+        } else {
+            Accuracy::Rough
+        };
+
+        f.pad(&self.to_text_en(accuracy).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl From<Date> for HumanDate {
+    fn from(date: Date) -> Self {
+        Self::new(date, jiff::Zoned::now().date())
+    }
+}
+
+impl Humanize for Date {
+    fn humanize(&self) -> String {
+        format!("{}", HumanDate::from(*self))
+    }
+}
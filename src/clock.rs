@@ -0,0 +1,126 @@
+use jiff::civil::Time;
+
+use crate::Humanize;
+
+/// Formatter giving spoken clock time, such as "noon", "quarter past five", "half past three"
+/// or "ten to six"
+///
+/// By default the time is rounded to the nearest five minutes. In exact mode times off the
+/// five minute marks fall back to numeric text, such as "5:32 PM", or "17:32" with the
+/// 24-hour clock.
+///
+/// ```
+/// use jiff::civil::time;
+/// use jiffy::FuzzyClock;
+///
+/// let clock = FuzzyClock::new();
+/// assert_eq!("quarter past five", clock.to_text_en(time(17, 14, 0, 0)));
+/// assert_eq!("noon", clock.to_text_en(time(11, 58, 0, 0)));
+///
+/// let clock = clock.exact(true).hour24(true);
+/// assert_eq!("ten to six", clock.to_text_en(time(17, 50, 0, 0)));
+/// assert_eq!("17:32", clock.to_text_en(time(17, 32, 0, 0)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FuzzyClock {
+    exact: bool,
+    hour24: bool,
+}
+
+impl FuzzyClock {
+    /// Create a formatter rounding to the nearest five minutes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Do not round the time, falling back to numeric text off the five minute marks
+    #[must_use]
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    /// Use the 24-hour clock for numeric text
+    #[must_use]
+    pub fn hour24(mut self, hour24: bool) -> Self {
+        self.hour24 = hour24;
+        self
+    }
+
+    /// Gives English text representation of `time`
+    pub fn to_text_en(&self, time: Time) -> String {
+        let on_mark = time.minute() % 5 == 0 && time.second() == 0 && time.subsec_nanosecond() == 0;
+        if self.exact && !on_mark {
+            return self.to_numeric_en(time);
+        }
+
+        // Minutes since midnight, rounded to the nearest five
+        let minutes = i32::from(time.hour()) * 60 + i32::from(time.minute());
+        let minutes = if time.second() >= 30 {
+            minutes + 1
+        } else {
+            minutes
+        };
+        let minutes = (minutes + 2) / 5 * 5 % (24 * 60);
+        let (hour, minute) = (minutes / 60, minutes % 60);
+
+        match minute {
+            0 => match hour {
+                0 => "midnight".to_string(),
+                12 => "noon".to_string(),
+                hour => format!("{} o'clock", hour_name_en(hour)),
+            },
+            15 => format!("quarter past {}", hour_name_en(hour)),
+            30 => format!("half past {}", hour_name_en(hour)),
+            45 => format!("quarter to {}", hour_name_en(hour + 1)),
+            minute if minute < 30 => {
+                format!("{} past {}", minutes_name_en(minute), hour_name_en(hour))
+            }
+            minute => format!(
+                "{} to {}",
+                minutes_name_en(60 - minute),
+                hour_name_en(hour + 1)
+            ),
+        }
+    }
+
+    /// Gives numeric text representation of `time`, such as "5:32 PM" or "17:32"
+    pub fn to_numeric_en(&self, time: Time) -> String {
+        if self.hour24 {
+            return format!("{:02}:{:02}", time.hour(), time.minute());
+        }
+        let meridiem = if time.hour() < 12 { "AM" } else { "PM" };
+        let hour = match time.hour() % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        format!("{}:{:02} {}", hour, time.minute(), meridiem)
+    }
+}
+
+impl Humanize for Time {
+    fn humanize(&self) -> String {
+        FuzzyClock::new().to_text_en(*self)
+    }
+}
+
+/// Name of the hour on the 12-hour clock, with "midnight" and "noon" for 0 and 12
+fn hour_name_en(hour: i32) -> &'static str {
+    match hour % 24 {
+        0 => "midnight",
+        12 => "noon",
+        hour => [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+        ][(hour % 12 - 1) as usize],
+    }
+}
+
+/// Name of a multiple of five minutes, up to twenty-five
+fn minutes_name_en(minutes: i32) -> &'static str {
+    match minutes {
+        5 => "five",
+        10 => "ten",
+        20 => "twenty",
+        _ => "twenty-five",
+    }
+}
@@ -0,0 +1,140 @@
+use crate::parse::{decimal_nanos, Amounts};
+use crate::Error;
+
+/// Parse a compact duration such as "1h30m", "2d 4h", "90s" or "-1y 2mo", as given by
+/// `HumanTime::to_text_compact`
+///
+/// Accepted units are `y`, `mo`, `w`, `d`, `h`, `m`, `s`, `ms`, `us` (or `µs`) and `ns`, with
+/// `min` and `sec` as aliases of `m` and `s`. Years, months and weeks are counted in days, the
+/// way `HumanTime` counts them. Counts of hours and shorter units may have a decimal fraction,
+/// as in "1.5h".
+///
+/// ```
+/// use jiff::ToSpan;
+///
+/// let span = jiffy::parse_compact("1h30m").unwrap();
+/// assert_eq!(1.hours().minutes(30), span);
+///
+/// let span = jiffy::parse_compact("-2d 4h").unwrap();
+/// assert_eq!((-2).days().hours(4), span);
+/// ```
+pub fn parse_compact(text: &str) -> Result<jiff::Span, Error> {
+    let mut scanner = Scanner { text, position: 0 };
+
+    scanner.skip_whitespace();
+    let negative = scanner.eat('-');
+    if !negative {
+        scanner.eat('+');
+    }
+
+    let mut amounts = Amounts::default();
+    let mut empty = true;
+    loop {
+        scanner.skip_whitespace();
+        if scanner.peek().is_none() {
+            break;
+        }
+
+        let (digits, digits_position) = scanner.take(|c| c.is_ascii_digit());
+        let decimal = scanner.eat('.');
+        let digits = if decimal {
+            let (fraction, _) = scanner.take(|c| c.is_ascii_digit());
+            &text[digits_position..digits_position + digits.len() + 1 + fraction.len()]
+        } else {
+            digits
+        };
+
+        scanner.skip_whitespace();
+        let (unit, unit_position) = scanner.take(char::is_alphabetic);
+        let unit = match unit {
+            "ns" => jiff::Unit::Nanosecond,
+            "us" | "µs" => jiff::Unit::Microsecond,
+            "ms" => jiff::Unit::Millisecond,
+            "s" | "sec" => jiff::Unit::Second,
+            "m" | "min" => jiff::Unit::Minute,
+            "h" => jiff::Unit::Hour,
+            "d" => jiff::Unit::Day,
+            "w" => jiff::Unit::Week,
+            "mo" => jiff::Unit::Month,
+            "y" => jiff::Unit::Year,
+            "" if scanner.peek().is_none() => {
+                return Err(Error::UnexpectedEnd { expected: "a unit" })
+            }
+            _ => return Err(scanner.unexpected(unit_position, "a unit")),
+        };
+
+        let added = if decimal {
+            if unit > jiff::Unit::Hour {
+                return Err(scanner.unexpected(unit_position, "a unit of an hour or shorter"));
+            }
+            let nanos = decimal_nanos(digits, unit)
+                .ok_or_else(|| scanner.unexpected(digits_position, "a number"))?;
+            amounts.add_nanos(nanos)
+        } else {
+            let expected = if digits.is_empty() {
+                "a number"
+            } else {
+                "a smaller number"
+            };
+            let count = digits
+                .parse::<i64>()
+                .map_err(|_| scanner.unexpected(digits_position, expected))?;
+            amounts.add(unit, count)
+        };
+        added.ok_or_else(|| scanner.unexpected(digits_position, "a smaller number"))?;
+        empty = false;
+    }
+
+    if empty {
+        return Err(Error::UnexpectedEnd {
+            expected: "a duration",
+        });
+    }
+
+    let span = amounts.to_span()?;
+    Ok(if negative { span.negate() } else { span })
+}
+
+struct Scanner<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take(char::is_whitespace);
+    }
+
+    /// Take the longest run of characters matching `predicate`, with its position
+    fn take(&mut self, predicate: impl Fn(char) -> bool) -> (&'a str, usize) {
+        let start = self.position;
+        let rest = &self.text[start..];
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += len;
+        (&rest[..len], start)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Error for the whitespace delimited token starting at `position`
+    fn unexpected(&self, position: usize, expected: &'static str) -> Error {
+        let rest = &self.text[position..];
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        Error::UnexpectedToken {
+            token: rest[..len].to_string(),
+            position,
+            expected,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{CalendarTime, Error};
+
+/// Part of the day
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DayPart {
+    Morning,
+    Afternoon,
+    Evening,
+    /// Runs past midnight, so the early hours belong to the night of the day before
+    Night,
+}
+
+impl DayPart {
+    /// Gives English name of the day part, such as "morning"
+    pub fn to_text_en(self) -> &'static str {
+        match self {
+            Self::Morning => "morning",
+            Self::Afternoon => "afternoon",
+            Self::Evening => "evening",
+            Self::Night => "night",
+        }
+    }
+}
+
+/// Formatter combining the part of the day with the calendar day, such as "this morning",
+/// "tonight", "last night" or "tomorrow afternoon"
+///
+/// Parts of the day start at 5:00, 12:00, 17:00 and 21:00 by default, as seen in the time
+/// zone of the reference. The night runs until the morning, so 2:00 belongs to the night of
+/// the day before, and a point in time in the same night as the reference is "tonight", even
+/// past midnight. Points in time further than a day away are described by `CalendarTime` for
+/// the day their part of the day belongs to, as in "last Tuesday".
+///
+/// ```
+/// use jiff::civil::date;
+/// use jiffy::DayPartFormat;
+///
+/// let now = date(2026, 10, 18).at(10, 0, 0, 0).in_tz("UTC").unwrap();
+/// let format = DayPartFormat::new();
+///
+/// let dt = date(2026, 10, 18).at(7, 30, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("this morning", format.format(&dt, &now).unwrap());
+///
+/// let dt = date(2026, 10, 18).at(1, 30, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("last night", format.format(&dt, &now).unwrap());
+///
+/// let dt = date(2026, 10, 18).at(22, 0, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("tonight", format.format(&dt, &now).unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DayPartFormat {
+    morning: i8,
+    afternoon: i8,
+    evening: i8,
+    night: i8,
+}
+
+impl Default for DayPartFormat {
+    fn default() -> Self {
+        Self {
+            morning: 5,
+            afternoon: 12,
+            evening: 17,
+            night: 21,
+        }
+    }
+}
+
+impl DayPartFormat {
+    /// Create a formatter with the default parts of the day
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the hour the morning starts, which is also when the night ends
+    #[must_use]
+    pub fn morning(mut self, hour: i8) -> Self {
+        self.morning = hour;
+        self
+    }
+
+    /// Set the hour the afternoon starts
+    #[must_use]
+    pub fn afternoon(mut self, hour: i8) -> Self {
+        self.afternoon = hour;
+        self
+    }
+
+    /// Set the hour the evening starts
+    #[must_use]
+    pub fn evening(mut self, hour: i8) -> Self {
+        self.evening = hour;
+        self
+    }
+
+    /// Set the hour the night starts
+    #[must_use]
+    pub fn night(mut self, hour: i8) -> Self {
+        self.night = hour;
+        self
+    }
+
+    /// Gives the part of the day `hour` falls in
+    pub fn day_part(&self, hour: i8) -> DayPart {
+        if hour >= self.morning && hour < self.afternoon {
+            DayPart::Morning
+        } else if hour >= self.afternoon && hour < self.evening {
+            DayPart::Afternoon
+        } else if hour >= self.evening && hour < self.night {
+            DayPart::Evening
+        } else {
+            DayPart::Night
+        }
+    }
+
+    /// Gives text for `dt`, relative to `reference`
+    pub fn format(&self, dt: &jiff::Zoned, reference: &jiff::Zoned) -> Result<String, Error> {
+        self.validate()?;
+
+        let local = dt.with_time_zone(reference.time_zone().clone());
+        let part = self.day_part(local.hour());
+        let shifted = self.shift_night(&local)?;
+        // In the early hours the reference is still in the night before, so that night and the
+        // ones before it count from there, while later parts of the day count from its date
+        let reference_night = self.shift_night(reference)?.date();
+        let reference_day = if part == DayPart::Night && shifted.date() <= reference_night {
+            reference_night
+        } else {
+            reference.date()
+        };
+        let days = shifted.date().since(reference_day)?.get_days();
+
+        let text = match (days, part) {
+            (0, DayPart::Night) => "tonight".to_string(),
+            (0, part) => format!("this {}", part.to_text_en()),
+            (-1, DayPart::Night) => "last night".to_string(),
+            (-1, part) => format!("yesterday {}", part.to_text_en()),
+            (1, part) => format!("tomorrow {}", part.to_text_en()),
+            _ => CalendarTime::new(&shifted, reference).to_text_en()?,
+        };
+        Ok(text)
+    }
+
+    /// `dt` moved to the day its part of the day belongs to, which is the day before for the
+    /// early hours of the night
+    fn shift_night(&self, dt: &jiff::Zoned) -> Result<jiff::Zoned, Error> {
+        if dt.hour() < self.morning {
+            Ok(dt.yesterday()?)
+        } else {
+            Ok(dt.clone())
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let hours = [self.morning, self.afternoon, self.evening, self.night];
+        let in_order = hours.windows(2).all(|pair| pair[0] < pair[1]);
+        if !in_order || self.morning < 0 || self.night > 23 {
+            return Err(Error::InvalidArgument(format!(
+                "parts of the day must start at increasing hours of the day, got {:?}",
+                hours
+            )));
+        }
+        Ok(())
+    }
+}
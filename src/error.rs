@@ -4,6 +4,20 @@ use thiserror::Error;
 pub enum Error {
     #[error("{0}")]
     InvalidArgument(String),
+    #[error("expected {expected}, found `{token}` at position {position}")]
+    UnexpectedToken {
+        token: String,
+        position: usize,
+        expected: &'static str,
+    },
+    #[error("expected {expected}, found end of input")]
+    UnexpectedEnd { expected: &'static str },
+    #[error("ambiguous `{token}` at position {position}, {hint}")]
+    Ambiguous {
+        token: String,
+        position: usize,
+        hint: &'static str,
+    },
 }
 
 impl std::convert::From<jiff::Error> for Error {
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::humantime::{unit_seconds, S_DAY};
+use crate::{Accuracy, Error, FormatOptions, HumanTime};
+
+/// Default format of the absolute date shown as tooltip, such as "Sat, 18 Oct 2026 10:00 UTC"
+const TOOLTIP_FORMAT: &str = "%a, %d %b %Y %H:%M %Z";
+
+/// HTML `<time>` element showing `HumanTime` text along with the machine-readable date, or
+/// duration, it stands for
+///
+/// ```
+/// use jiff::civil::date;
+/// use jiffy::HtmlTime;
+///
+/// let now = date(2026, 10, 18).at(13, 0, 0, 0).in_tz("UTC").unwrap();
+/// let dt = date(2026, 10, 18).at(10, 0, 0, 0).in_tz("UTC").unwrap();
+/// let html = HtmlTime::new(&dt, &now).unwrap().tooltip(true).to_html().unwrap();
+/// assert_eq!(
+///     r#"<time datetime="2026-10-18T10:00:00Z" title="Sun, 18 Oct 2026 10:00 UTC">3 hours ago</time>"#,
+///     html
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct HtmlTime {
+    human_time: HumanTime,
+    // `None` when the element stands for a duration
+    datetime: Option<jiff::Zoned>,
+    accuracy: Accuracy,
+    options: FormatOptions,
+    tooltip: Option<String>,
+}
+
+impl HtmlTime {
+    /// Create an element for `dt`, described relative to `reference`
+    pub fn new(dt: &jiff::Zoned, reference: &jiff::Zoned) -> Result<Self, Error> {
+        let human_time = HumanTime::from_zoned(dt, reference)?;
+        Ok(Self::with(human_time, Some(dt.clone())))
+    }
+
+    /// Create an element for `ts`, described relative to `reference`. The tooltip shows
+    /// the date in UTC.
+    pub fn from_timestamp(ts: jiff::Timestamp, reference: jiff::Timestamp) -> Result<Self, Error> {
+        let human_time = HumanTime::from(ts.since(reference)?);
+        Ok(Self::with(
+            human_time,
+            Some(ts.to_zoned(jiff::tz::TimeZone::UTC)),
+        ))
+    }
+
+    /// Create an element for a duration, which has no tense and no tooltip
+    pub fn from_span(span: jiff::Span) -> Self {
+        Self::with(HumanTime::from(span), None)
+    }
+
+    fn with(human_time: HumanTime, datetime: Option<jiff::Zoned>) -> Self {
+        Self {
+            human_time,
+            datetime,
+            accuracy: Accuracy::Rough,
+            options: FormatOptions::default(),
+            tooltip: None,
+        }
+    }
+
+    /// Set the accuracy of the text, rough by default
+    #[must_use]
+    pub fn accuracy(mut self, accuracy: Accuracy) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Set the options refining the text
+    #[must_use]
+    pub fn options(mut self, options: FormatOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Show the absolute date as tooltip, such as "Sat, 18 Oct 2026 10:00 UTC"
+    #[must_use]
+    pub fn tooltip(mut self, yes: bool) -> Self {
+        self.tooltip = if yes {
+            Some(TOOLTIP_FORMAT.to_string())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Show the absolute date as tooltip, in the given `jiff::fmt::strtime` format
+    #[must_use]
+    pub fn tooltip_format(mut self, format: impl Into<String>) -> Self {
+        self.tooltip = Some(format.into());
+        self
+    }
+
+    /// Gives the HTML of the element
+    pub fn to_html(&self) -> Result<String, Error> {
+        let ht = self.human_time;
+        let (datetime, tense) = match &self.datetime {
+            Some(dt) => (
+                dt.timestamp().to_string(),
+                ht.tense_with(self.accuracy, self.options)?,
+            ),
+            None => (duration_string(ht)?, crate::Tense::Present),
+        };
+        let text = ht.to_text_en_with(self.accuracy, tense, self.options)?;
+
+        let mut html = format!(r#"<time datetime="{}""#, escape(&datetime));
+        if let (Some(format), Some(dt)) = (&self.tooltip, &self.datetime) {
+            let title = jiff::fmt::strtime::format(format, dt)?;
+            html.push_str(&format!(r#" title="{}""#, escape(&title)));
+        }
+        html.push_str(&format!(">{}</time>", escape(&text)));
+        Ok(html)
+    }
+}
+
+impl fmt::Display for HtmlTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_html().map_err(|_| fmt::Error)?)
+    }
+}
+
+/// HTML duration string for `ht`, such as "P3DT2H30M". It has no sign, and years, months and
+/// weeks are counted in days, as HTML allows neither. Seconds keep up to three digits of
+/// fraction.
+fn duration_string(ht: HumanTime) -> Result<String, Error> {
+    let (mut days, mut hours, mut minutes, mut seconds, mut millis) = (0, 0, 0, 0, 0);
+    for (unit, n) in ht.precise_amounts()? {
+        match unit {
+            jiff::Unit::Hour => hours = n,
+            jiff::Unit::Minute => minutes = n,
+            jiff::Unit::Second => seconds = n,
+            jiff::Unit::Millisecond => millis = n,
+            unit if unit >= jiff::Unit::Day => days += n * unit_seconds(unit) / S_DAY,
+            _ => {}
+        }
+    }
+
+    let mut time = String::new();
+    if hours > 0 {
+        time.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        time.push_str(&format!("{}M", minutes));
+    }
+    if millis > 0 {
+        time.push_str(&format!("{}.{:03}S", seconds, millis));
+    } else if seconds > 0 {
+        time.push_str(&format!("{}S", seconds));
+    }
+
+    let mut text = "P".to_string();
+    if days > 0 {
+        text.push_str(&format!("{}D", days));
+    }
+    if !time.is_empty() {
+        text.push_str(&format!("T{}", time));
+    } else if days == 0 {
+        text.push_str("T0S");
+    }
+    Ok(text)
+}
+
+/// Escape `text` for use in HTML content and quoted attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
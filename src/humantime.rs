@@ -7,10 +7,11 @@ use std::time::SystemTime;
 
 use jiff::ToSpan;
 
-use crate::Humanize;
+use crate::{FormatOptions, Humanize};
 
 /// Indicates the time of the period in relation to the time of the utterance
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tense {
     Past,
     Present,
@@ -19,6 +20,7 @@ pub enum Tense {
 
 /// The accuracy of the representation
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Accuracy {
     /// Rough approximation, easy to grasp, but not necessarily accurate
     Rough,
@@ -26,6 +28,59 @@ pub enum Accuracy {
     Precise,
 }
 
+/// Qualifies a rough representation that is not exact
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Qualifier {
+    /// The exact time is close to the representation
+    About,
+    /// The exact time is noticeably shorter than the representation
+    Almost,
+    /// The exact time is noticeably longer than the representation
+    Over,
+}
+
+impl Qualifier {
+    /// Gives English text representation of the qualifier
+    pub fn to_text_en(self) -> &'static str {
+        match self {
+            Self::About => "about",
+            Self::Almost => "almost",
+            Self::Over => "over",
+        }
+    }
+}
+
+/// Wording of the rough representation of spans within the now window, see
+/// `FormatOptions::now_wording`
+///
+/// | Wording   | Past             | Present     | Future             |
+/// |-----------|------------------|-------------|--------------------|
+/// | `Now`     | "now"            | "now"       | "now"              |
+/// | `JustNow` | "just now"       | "just now"  | "in a moment"      |
+/// | `Moment`  | "a moment ago"   | "now"       | "moments from now" |
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NowWording {
+    Now,
+    JustNow,
+    Moment,
+}
+
+impl NowWording {
+    /// Gives English text of the wording for a span in the given `tense`, which already
+    /// tells the tense
+    pub fn to_text_en(self, tense: Tense) -> &'static str {
+        match (self, tense) {
+            (Self::Now, _) | (Self::Moment, Tense::Present) => "now",
+            (Self::JustNow, Tense::Past) | (Self::JustNow, Tense::Present) => "just now",
+            (Self::JustNow, Tense::Future) => "in a moment",
+            (Self::Moment, Tense::Past) => "a moment ago",
+            (Self::Moment, Tense::Future) => "moments from now",
+        }
+    }
+}
+
 impl Accuracy {
     /// Returns whether this accuracy is precise
     #[must_use]
@@ -44,14 +99,132 @@ impl Accuracy {
 // Jiff uses `i16` year and `i32` for month, week, day, and hour.
 // The variables here use i64 just to make the intermediate calculation easy.
 // The value will be cast to desired representation at later time
-const S_MINUTE: i64 = 60;
-const S_HOUR: i64 = S_MINUTE * 60;
-const S_DAY: i64 = S_HOUR * 24;
-const S_WEEK: i64 = S_DAY * 7;
-const S_MONTH: i64 = S_DAY * 30;
-const S_YEAR: i64 = S_DAY * 365;
-
-#[derive(Clone, Copy, Debug)]
+pub(crate) const S_MINUTE: i64 = 60;
+pub(crate) const S_HOUR: i64 = S_MINUTE * 60;
+pub(crate) const S_DAY: i64 = S_HOUR * 24;
+pub(crate) const S_WEEK: i64 = S_DAY * 7;
+pub(crate) const S_MONTH: i64 = S_DAY * 30;
+pub(crate) const S_YEAR: i64 = S_DAY * 365;
+pub(crate) const S_QUARTER: i64 = S_DAY * 91;
+pub(crate) const S_DECADE: i64 = S_YEAR * 10;
+pub(crate) const S_CENTURY: i64 = S_YEAR * 100;
+
+// Units considered by the fractional representation, largest first
+const FRACTIONAL_UNITS: [jiff::Unit; 10] = [
+    jiff::Unit::Year,
+    jiff::Unit::Month,
+    jiff::Unit::Week,
+    jiff::Unit::Day,
+    jiff::Unit::Hour,
+    jiff::Unit::Minute,
+    jiff::Unit::Second,
+    jiff::Unit::Millisecond,
+    jiff::Unit::Microsecond,
+    jiff::Unit::Nanosecond,
+];
+
+/// Number of seconds in `unit` as counted by the rough representation. Units below a second
+/// count as a whole second.
+pub(crate) fn unit_seconds(unit: jiff::Unit) -> i64 {
+    match unit {
+        jiff::Unit::Year => S_YEAR,
+        jiff::Unit::Month => S_MONTH,
+        jiff::Unit::Week => S_WEEK,
+        jiff::Unit::Day => S_DAY,
+        jiff::Unit::Hour => S_HOUR,
+        jiff::Unit::Minute => S_MINUTE,
+        _ => 1,
+    }
+}
+
+/// Number of nanoseconds in `unit`, with the unit lengths of the fractional representation
+fn fractional_nanos(unit: jiff::Unit) -> f64 {
+    match unit {
+        jiff::Unit::Millisecond => 1e6,
+        jiff::Unit::Microsecond => 1e3,
+        jiff::Unit::Nanosecond => 1.0,
+        unit => unit_seconds(unit) as f64 * 1e9,
+    }
+}
+
+/// Convert `n` to the integer type of a `TimePeriod` amount
+fn amount<T: TryFrom<i64>>(n: i64) -> Result<T, crate::Error> {
+    T::try_from(n)
+        .map_err(|_| crate::Error::InvalidArgument(format!("amount {} is out of range", n)))
+}
+
+/// `n` with thousands separated by commas, such as "9,000"
+fn thousands_en(n: i32) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut text = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            text.push(',');
+        }
+        text.push(digit);
+    }
+    text
+}
+
+/// Number of nanoseconds in `unit`, a second or below
+fn subsec_nanos(unit: jiff::Unit) -> f64 {
+    match unit {
+        jiff::Unit::Second => 1e9,
+        jiff::Unit::Millisecond => 1e6,
+        jiff::Unit::Microsecond => 1e3,
+        _ => 1.0,
+    }
+}
+
+/// `value` rounded to `digits` significant digits, without trailing zeros after the decimal
+/// point, such as "1.25" or "250"
+fn significant_en(value: f64, digits: u8) -> String {
+    let magnitude = if value > 0.0 {
+        value.log10().floor() as i32
+    } else {
+        0
+    };
+    let decimals = max(i32::from(digits) - 1 - magnitude, 0) as usize;
+    let text = format!("{:.*}", decimals, value);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+/// English indefinite article for a single `unit`
+fn article_en(unit: jiff::Unit) -> &'static str {
+    match unit {
+        jiff::Unit::Hour => "an",
+        _ => "a",
+    }
+}
+
+/// English name of `unit`, in plural form when `plural` is set
+fn unit_name_en(unit: jiff::Unit, plural: bool) -> &'static str {
+    match (unit, plural) {
+        (jiff::Unit::Nanosecond, _) => "ns",
+        (jiff::Unit::Microsecond, _) => "µs",
+        (jiff::Unit::Millisecond, _) => "ms",
+        (jiff::Unit::Second, false) => "second",
+        (jiff::Unit::Second, true) => "seconds",
+        (jiff::Unit::Minute, false) => "minute",
+        (jiff::Unit::Minute, true) => "minutes",
+        (jiff::Unit::Hour, false) => "hour",
+        (jiff::Unit::Hour, true) => "hours",
+        (jiff::Unit::Day, false) => "day",
+        (jiff::Unit::Day, true) => "days",
+        (jiff::Unit::Week, false) => "week",
+        (jiff::Unit::Week, true) => "weeks",
+        (jiff::Unit::Month, false) => "month",
+        (jiff::Unit::Month, true) => "months",
+        (jiff::Unit::Year, false) => "year",
+        (jiff::Unit::Year, true) => "years",
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 enum TimePeriod {
     Now,
     Nanos(i64),
@@ -64,6 +237,23 @@ enum TimePeriod {
     Weeks(i32),
     Months(i32),
     Years(i16),
+    /// Number of quarters of the unit, rendered as an idiom
+    Quarters(jiff::Unit, i64),
+    /// Quarters of a year, 91 days each
+    YearQuarters(i32),
+    Decades(i32),
+    Centuries(i32),
+    /// More than the number of years, a multiple of a thousand, for very distant times
+    OverYears(i32),
+    /// Span within the now window, in the wording and tense it is given in
+    Moment(NowWording, Tense),
+    /// Span under 20 seconds past the now window, rendered as "a few seconds"
+    FewSeconds,
+    /// Span of 40 seconds or more, but not yet a rough minute
+    LessThanMinute,
+    /// Amount of a unit of a second or below, with a fraction, rounded to the number of
+    /// significant digits
+    Decimal(jiff::Unit, f64, u8),
     Eternity,
 }
 
@@ -87,7 +277,19 @@ impl TimePeriod {
             Self::Months(1) => "1 month".into(),
             Self::Months(n) => format!("{} months", n).into(),
             Self::Years(1) => "1 year".into(),
-            Self::Years(n) => format!("{} years", n).into(),
+            Self::Years(n) => format!("{} years", thousands_en(i32::from(n))).into(),
+            Self::Quarters(unit, n) => Self::quarters_text_en(unit, n).into(),
+            Self::YearQuarters(1) => "1 quarter".into(),
+            Self::YearQuarters(n) => format!("{} quarters", n).into(),
+            Self::Decades(1) => "1 decade".into(),
+            Self::Decades(n) => format!("{} decades", n).into(),
+            Self::Centuries(1) => "1 century".into(),
+            Self::Centuries(n) => format!("{} centuries", n).into(),
+            Self::OverYears(n) => format!("over {} years", thousands_en(n)).into(),
+            Self::Moment(wording, tense) => wording.to_text_en(tense).into(),
+            Self::FewSeconds => "a few seconds".into(),
+            Self::LessThanMinute => "less than a minute".into(),
+            Self::Decimal(unit, value, digits) => Self::decimal_text_en(unit, value, digits).into(),
             Self::Eternity => "eternity".into(),
         }
     }
@@ -110,11 +312,202 @@ impl TimePeriod {
             Self::Months(1) => "a month".into(),
             Self::Months(n) => format!("{} months", n).into(),
             Self::Years(1) => "a year".into(),
-            Self::Years(n) => format!("{} years", n).into(),
+            Self::Years(n) => format!("{} years", thousands_en(i32::from(n))).into(),
+            Self::Quarters(unit, n) => Self::quarters_text_en(unit, n).into(),
+            Self::YearQuarters(1) => "a quarter".into(),
+            Self::YearQuarters(n) => format!("{} quarters", n).into(),
+            Self::Decades(1) => "a decade".into(),
+            Self::Decades(n) => format!("{} decades", n).into(),
+            Self::Centuries(1) => "a century".into(),
+            Self::Centuries(n) => format!("{} centuries", n).into(),
+            Self::OverYears(n) => format!("over {} years", thousands_en(n)).into(),
+            Self::Moment(wording, tense) => wording.to_text_en(tense).into(),
+            Self::FewSeconds => "a few seconds".into(),
+            Self::LessThanMinute => "less than a minute".into(),
+            Self::Decimal(unit, value, digits) => Self::decimal_text_en(unit, value, digits).into(),
+            Self::Eternity => "eternity".into(),
+        }
+    }
+
+    fn to_text_narrow(self) -> Cow<'static, str> {
+        match self {
+            Self::Now | Self::Moment(..) => "0s".into(),
+            Self::FewSeconds => "10s".into(),
+            Self::LessThanMinute => "45s".into(),
+            Self::Nanos(n) => format!("{}ns", n).into(),
+            Self::Micros(n) => format!("{}µs", n).into(),
+            Self::Millis(n) => format!("{}ms", n).into(),
+            Self::Seconds(n) => format!("{}s", n).into(),
+            Self::Minutes(n) => format!("{}m", n).into(),
+            Self::Hours(n) => format!("{}h", n).into(),
+            Self::Days(n) => format!("{}d", n).into(),
+            Self::Weeks(n) => format!("{}w", n).into(),
+            Self::Months(n) => format!("{}mo", n).into(),
+            Self::Years(n) => format!("{}y", n).into(),
+            Self::Quarters(unit, n) => Self::Seconds(n * unit_seconds(unit) / 4).to_text_narrow(),
+            // Compact text has no units for these, so they are given in units it has
+            Self::YearQuarters(n) => Self::Days(n * 91).to_text_narrow(),
+            Self::Decades(n) => format!("{}y", i64::from(n) * 10).into(),
+            Self::Centuries(n) => format!("{}y", i64::from(n) * 100).into(),
+            Self::OverYears(n) => format!("{}y", n).into(),
+            Self::Decimal(unit, value, digits) => {
+                let name = match unit {
+                    jiff::Unit::Second => "s",
+                    unit => unit_name_en(unit, true),
+                };
+                format!("{}{}", significant_en(value, digits), name).into()
+            }
             Self::Eternity => "eternity".into(),
         }
     }
 
+    fn quarters_text_en(unit: jiff::Unit, quarters: i64) -> String {
+        let single = format!("{} {}", article_en(unit), unit_name_en(unit, false));
+        match quarters {
+            1 => format!("a quarter of {}", single),
+            2 => format!("half {}", single),
+            3 => format!("three quarters of {}", single),
+            6 => format!("{} and a half", single),
+            n => format!("{} and a half {}", n / 4, unit_name_en(unit, true)),
+        }
+    }
+
+    /// Amount of `nanos` nanoseconds in the largest unit of a second or below that it
+    /// amounts to at least one of, rounded to `digits` significant digits
+    fn decimal(nanos: f64, digits: u8) -> Result<Self, crate::Error> {
+        if digits == 0 {
+            return Err(crate::Error::InvalidArgument(
+                "significant digits must be at least 1".to_string(),
+            ));
+        }
+        let nanos = if nanos > 0.0 {
+            let magnitude = nanos.log10().floor() as i32;
+            let factor = 10f64.powi(magnitude + 1 - i32::from(digits));
+            (nanos / factor).round() * factor
+        } else {
+            nanos
+        };
+
+        let unit = [
+            jiff::Unit::Second,
+            jiff::Unit::Millisecond,
+            jiff::Unit::Microsecond,
+        ]
+        .iter()
+        .copied()
+        .find(|&unit| nanos >= subsec_nanos(unit))
+        .unwrap_or(jiff::Unit::Nanosecond);
+        Ok(Self::Decimal(unit, nanos / subsec_nanos(unit), digits))
+    }
+
+    /// "1.25 seconds", "1 second" or "250 ms"
+    fn decimal_text_en(unit: jiff::Unit, value: f64, digits: u8) -> String {
+        let amount = significant_en(value, digits);
+        // English takes the singular form only for a plain "1", as in fractional text
+        let name = unit_name_en(unit, amount != "1");
+        format!("{} {}", amount, name)
+    }
+
+    /// Number of seconds the period stands for, if it is a measurable amount
+    fn seconds(self) -> Option<i64> {
+        match self {
+            Self::Seconds(n) => Some(n),
+            Self::Minutes(n) => Some(n * S_MINUTE),
+            Self::Hours(n) => Some(i64::from(n) * S_HOUR),
+            Self::Days(n) => Some(i64::from(n) * S_DAY),
+            Self::Weeks(n) => Some(i64::from(n) * S_WEEK),
+            Self::Months(n) => Some(i64::from(n) * S_MONTH),
+            Self::Years(n) => Some(i64::from(n) * S_YEAR),
+            Self::OverYears(n) => Some(i64::from(n) * S_YEAR),
+            Self::Quarters(unit, n) => Some(n * unit_seconds(unit) / 4),
+            Self::YearQuarters(n) => Some(i64::from(n) * S_QUARTER),
+            Self::Decades(n) => Some(i64::from(n) * S_DECADE),
+            Self::Centuries(n) => Some(i64::from(n) * S_CENTURY),
+            Self::Now | Self::Moment(..) | Self::Eternity => None,
+            Self::FewSeconds | Self::LessThanMinute => None,
+            Self::Nanos(_) | Self::Micros(_) | Self::Millis(_) | Self::Decimal(..) => None,
+        }
+    }
+
+    /// Number of seconds in the unit the period is counted in, if it is a measurable amount
+    fn unit_seconds(self) -> Option<i64> {
+        match self {
+            Self::YearQuarters(_) => Some(S_QUARTER),
+            Self::Decades(_) => Some(S_DECADE),
+            Self::Centuries(_) => Some(S_CENTURY),
+            period => period.unit().map(unit_seconds),
+        }
+    }
+
+    /// What the period stands for, as exposed by `Component::kind`
+    fn kind(self) -> ComponentKind {
+        match self {
+            Self::YearQuarters(_) => ComponentKind::Quarter,
+            Self::Decades(_) => ComponentKind::Decade,
+            Self::Centuries(_) => ComponentKind::Century,
+            Self::Now | Self::Moment(..) => ComponentKind::Now,
+            Self::FewSeconds => ComponentKind::FewSeconds,
+            Self::LessThanMinute => ComponentKind::LessThanMinute,
+            Self::Eternity => ComponentKind::Eternity,
+            period => period
+                .unit()
+                .map_or(ComponentKind::Eternity, ComponentKind::Unit),
+        }
+    }
+
+    /// Unit the period is counted in, if it is a measurable amount in a unit of jiff
+    fn unit(self) -> Option<jiff::Unit> {
+        match self {
+            Self::Nanos(_) => Some(jiff::Unit::Nanosecond),
+            Self::Micros(_) => Some(jiff::Unit::Microsecond),
+            Self::Millis(_) => Some(jiff::Unit::Millisecond),
+            Self::Seconds(_) => Some(jiff::Unit::Second),
+            Self::Minutes(_) => Some(jiff::Unit::Minute),
+            Self::Hours(_) => Some(jiff::Unit::Hour),
+            Self::Days(_) => Some(jiff::Unit::Day),
+            Self::Weeks(_) => Some(jiff::Unit::Week),
+            Self::Months(_) => Some(jiff::Unit::Month),
+            Self::Years(_) | Self::OverYears(_) => Some(jiff::Unit::Year),
+            Self::Quarters(unit, _) | Self::Decimal(unit, ..) => Some(unit),
+            Self::YearQuarters(_) | Self::Decades(_) | Self::Centuries(_) => None,
+            Self::FewSeconds | Self::LessThanMinute => None,
+            Self::Now | Self::Moment(..) | Self::Eternity => None,
+        }
+    }
+
+    /// Whole number of units in the period
+    fn count(self) -> i64 {
+        match self {
+            Self::Nanos(n) | Self::Micros(n) | Self::Millis(n) => n,
+            Self::Seconds(n) | Self::Minutes(n) => n,
+            Self::Hours(n) | Self::Days(n) | Self::Weeks(n) | Self::Months(n) => i64::from(n),
+            Self::Years(n) => i64::from(n),
+            Self::OverYears(n) => i64::from(n),
+            Self::YearQuarters(n) | Self::Decades(n) | Self::Centuries(n) => i64::from(n),
+            Self::Quarters(_, n) => n / 4,
+            Self::Decimal(_, value, _) => value as i64,
+            Self::Now | Self::Moment(..) | Self::Eternity => 0,
+            Self::FewSeconds | Self::LessThanMinute => 0,
+        }
+    }
+
+    /// Number of units in the period
+    fn value(self) -> f64 {
+        match self {
+            Self::Now | Self::Moment(..) => 0.0,
+            Self::FewSeconds | Self::LessThanMinute => 0.0,
+            Self::Nanos(n) | Self::Micros(n) | Self::Millis(n) => n as f64,
+            Self::Seconds(n) | Self::Minutes(n) => n as f64,
+            Self::Hours(n) | Self::Days(n) | Self::Weeks(n) | Self::Months(n) => f64::from(n),
+            Self::Years(n) => f64::from(n),
+            Self::OverYears(n) => f64::from(n),
+            Self::YearQuarters(n) | Self::Decades(n) | Self::Centuries(n) => f64::from(n),
+            Self::Quarters(_, n) => n as f64 / 4.0,
+            Self::Decimal(_, value, _) => value,
+            Self::Eternity => f64::INFINITY,
+        }
+    }
+
     fn to_text(self, accuracy: Accuracy) -> Cow<'static, str> {
         match accuracy {
             Accuracy::Rough => self.to_text_rough(),
@@ -123,13 +516,95 @@ impl TimePeriod {
     }
 }
 
+/// What a `Component` stands for, with the units jiff has no equivalent for
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ComponentKind {
+    /// Amount of a unit of jiff
+    Unit(jiff::Unit),
+    /// Quarters of a year, 91 days each
+    Quarter,
+    Decade,
+    Century,
+    /// "now", or the wording set for it
+    Now,
+    /// "a few seconds"
+    FewSeconds,
+    /// "less than a minute"
+    LessThanMinute,
+    Eternity,
+}
+
+/// A single unit of the representation of `HumanTime`, for rendering it without parsing
+/// the text
+///
+/// ```
+/// use jiff::ToSpan;
+/// use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+///
+/// let ht = HumanTime::from(1.days().hours(2));
+/// let html = ht
+///     .components(Accuracy::Precise, Tense::Future, FormatOptions::new())
+///     .unwrap()
+///     .map(|c| format!("<b>{}</b> {:?}", c.value(), c.unit().unwrap()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(vec!["<b>1</b> Day", "<b>2</b> Hour"], html);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Component {
+    period: TimePeriod,
+    tense: Tense,
+    qualifier: Option<Qualifier>,
+}
+
+impl Component {
+    /// Unit of the component, or `None` when it stands for "now", "eternity" or a sub-minute
+    /// bucket such as "a few seconds", or is counted in quarters, decades or centuries, which
+    /// jiff has no units for. See `kind` to tell these apart.
+    pub fn unit(&self) -> Option<jiff::Unit> {
+        self.period.unit()
+    }
+
+    /// What the component stands for, such as `ComponentKind::Unit(jiff::Unit::Hour)` or
+    /// `ComponentKind::Decade`
+    pub fn kind(&self) -> ComponentKind {
+        self.period.kind()
+    }
+
+    /// Number of units, which has a fraction for half and quarter idioms and for amounts
+    /// with significant digits. It is zero for
+    /// "now" and sub-minute buckets, and infinite for "eternity".
+    pub fn value(&self) -> f64 {
+        self.period.value()
+    }
+
+    /// Tense of the representation the component belongs to
+    pub fn tense(&self) -> Tense {
+        self.tense
+    }
+
+    /// Approximation of the component, if it is not exact
+    pub fn qualifier(&self) -> Option<Qualifier> {
+        self.qualifier
+    }
+
+    /// Gives English text of the component alone, without tense and qualifier
+    pub fn to_text_en(&self, accuracy: Accuracy) -> String {
+        self.period.to_text(accuracy).into_owned()
+    }
+}
+
 /// `Duration` wrapper that helps expressing the duration in human languages
+///
+/// With the `serde` feature it is serialized as the underlying span. See `jiffy::serde` for
+/// serializing it as text instead.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HumanTime(jiff::Span);
 
 impl HumanTime {
     const DAYS_IN_YEAR: i32 = 365;
     const DAYS_IN_MONTH: i32 = 30;
+    const DAYS_IN_QUARTER: i32 = 91;
 
     /// Create `HumanTime` object that corresponds to the current point in time.
     ///. Similar to `jiff::Zoned::now()`
@@ -137,37 +612,216 @@ impl HumanTime {
         Self(jiff::Span::default())
     }
 
+    /// Create `HumanTime` for `dt`, relative to `reference`. Days are counted as calendar
+    /// days in the time zone of `reference`, so a day across a DST transition is still a
+    /// day, even though it is 23 or 25 hours long.
+    pub fn from_zoned(dt: &jiff::Zoned, reference: &jiff::Zoned) -> Result<Self, crate::Error> {
+        let dt = dt.with_time_zone(reference.time_zone().clone());
+        Ok(Self(dt.since((jiff::Unit::Day, reference))?))
+    }
+
+    /// Create `HumanTime` for civil `dt`, relative to civil `reference`. Both are taken to
+    /// be in the same time zone.
+    pub fn from_civil(
+        dt: jiff::civil::DateTime,
+        reference: jiff::civil::DateTime,
+    ) -> Result<Self, crate::Error> {
+        Ok(Self(dt.since(reference)?))
+    }
+
     /// Gives English text representation of the `HumanTime` with given `accuracy` and 'tense`
     #[must_use = ""]
     pub fn to_text_en(self, accuracy: Accuracy, tense: Tense) -> Result<String, crate::Error> {
-        let mut periods = match accuracy {
-            Accuracy::Rough => self.rough_period()?,
-            Accuracy::Precise => self.precise_period()?,
-        };
+        self.to_text_en_with(accuracy, tense, FormatOptions::default())
+    }
 
-        let first = periods.remove(0).to_text(accuracy);
-        let last = periods.pop().map(|last| last.to_text(accuracy));
+    /// Gives English text representation of the `HumanTime` with given `accuracy` and 'tense`,
+    /// refined by `options`
+    #[must_use = ""]
+    pub fn to_text_en_with(
+        self,
+        accuracy: Accuracy,
+        tense: Tense,
+        options: FormatOptions,
+    ) -> Result<String, crate::Error> {
+        let mut components = self
+            .components(accuracy, tense, options)?
+            .collect::<Vec<_>>();
+
+        let first_component = components.remove(0);
+        let first = first_component.period.to_text(accuracy);
+        let last = components.pop().map(|last| last.period.to_text(accuracy));
 
-        let mut text = periods.into_iter().fold(first, |acc, p| {
-            format!("{}, {}", acc, p.to_text(accuracy)).into()
+        let mut text = components.into_iter().fold(first, |acc, c| {
+            format!("{}, {}", acc, c.period.to_text(accuracy)).into()
         });
 
         if let Some(last) = last {
             text = format!("{} and {}", text, last).into();
         }
 
+        if let Some(qualifier) = first_component.qualifier {
+            text = format!("{} {}", qualifier.to_text_en(), text).into();
+        }
+
+        if let TimePeriod::Moment(..) = first_component.period {
+            // The wording already tells the tense
+            return Ok(text.into_owned());
+        }
+        Ok(Self::with_tense_en(text, tense))
+    }
+
+    /// Gives the units the text representation of the `HumanTime` with given `accuracy`,
+    /// `tense` and `options` is made of, largest first
+    pub fn components(
+        self,
+        accuracy: Accuracy,
+        tense: Tense,
+        options: FormatOptions,
+    ) -> Result<impl Iterator<Item = Component>, crate::Error> {
+        let periods = match accuracy {
+            Accuracy::Rough => self.rough_period(tense, options)?,
+            Accuracy::Precise => self.precise_period(tense, options)?,
+        };
+
+        let qualifier = if accuracy.is_rough() && options.qualifiers {
+            self.qualifier(periods[0])?
+        } else {
+            None
+        };
+
+        Ok(periods
+            .into_iter()
+            .enumerate()
+            .map(move |(i, period)| Component {
+                period,
+                tense,
+                qualifier: qualifier.filter(|_| i == 0),
+            }))
+    }
+
+    /// Gives English text representation of the `HumanTime` as a fractional amount of a
+    /// single `unit`, rounded to `decimals` digits after the decimal point.
+    ///
+    /// When `unit` is `None` the largest unit the span amounts to at least one of is used.
+    ///
+    /// ```
+    /// use jiff::ToSpan;
+    /// use jiffy::{HumanTime, Tense};
+    ///
+    /// let ht = HumanTime::from(90.minutes());
+    /// assert_eq!("1.5 hours", ht.to_text_en_fractional(None, 1, Tense::Present).unwrap());
+    /// assert_eq!("in 90 minutes", ht.to_text_en_fractional(Some(jiff::Unit::Minute), 0, Tense::Future).unwrap());
+    /// ```
+    pub fn to_text_en_fractional(
+        self,
+        unit: Option<jiff::Unit>,
+        decimals: usize,
+        tense: Tense,
+    ) -> Result<String, crate::Error> {
+        let (mut unit, pick_unit) = match unit {
+            Some(unit) => (unit, false),
+            None => (self.fractional_unit()?, true),
+        };
+        let mut amount = format!("{:.*}", decimals, self.total(unit)?.abs());
+        // Rounding may reach the next larger unit, as 59m58s does with "60.0 minutes"
+        while let Some(larger) = FRACTIONAL_UNITS
+            .iter()
+            .position(|&u| u == unit)
+            .filter(|&i| pick_unit && i > 0)
+            .map(|i| FRACTIONAL_UNITS[i - 1])
+        {
+            let rounded = amount.parse::<f64>().unwrap_or_default();
+            if rounded < fractional_nanos(larger) / fractional_nanos(unit) {
+                break;
+            }
+            unit = larger;
+            amount = format!("{:.*}", decimals, self.total(unit)?.abs());
+        }
+        // English takes the singular form only for a plain "1", e.g. "1 hour" but "1.0 hours"
+        let name = unit_name_en(unit, amount != "1");
+
+        Ok(Self::with_tense_en(format!("{} {}", amount, name), tense))
+    }
+
+    /// Gives compact text representation of the `HumanTime` in narrow unit style, such as
+    /// "1h 30m" or "-2d 4h". The text can be parsed back with `jiffy::parse_compact`.
+    ///
+    /// ```
+    /// use jiff::ToSpan;
+    /// use jiffy::HumanTime;
+    ///
+    /// let ht = HumanTime::from((-2).days().hours(4));
+    /// assert_eq!("-2d 4h", ht.to_text_compact().unwrap());
+    /// ```
+    pub fn to_text_compact(self) -> Result<String, crate::Error> {
+        let text = self
+            .precise_period(Tense::Present, FormatOptions::default())?
+            .into_iter()
+            .map(TimePeriod::to_text_narrow)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if self.0.is_negative() {
+            Ok(format!("-{}", text))
+        } else {
+            Ok(text)
+        }
+    }
+
+    fn with_tense_en(text: impl Into<String>, tense: Tense) -> String {
+        let text = text.into();
         match tense {
-            Tense::Past => Ok(format!("{} ago", text)),
-            Tense::Future => Ok(format!("in {}", text)),
-            Tense::Present => Ok(text.into_owned()),
+            Tense::Past => format!("{} ago", text),
+            Tense::Future => format!("in {}", text),
+            Tense::Present => text,
         }
     }
 
-    fn tense(self, accuracy: Accuracy) -> Result<Tense, crate::Error> {
+    /// Total amount of `unit` in this `HumanTime`, using the same year, month and week
+    /// lengths as the rough representation
+    fn total(self, unit: jiff::Unit) -> Result<f64, crate::Error> {
+        let days_in_unit = match unit {
+            jiff::Unit::Year => Self::DAYS_IN_YEAR,
+            jiff::Unit::Month => Self::DAYS_IN_MONTH,
+            jiff::Unit::Week => 7,
+            _ => return Ok(self.0.total(unit)?),
+        };
+        Ok(self.0.total(jiff::Unit::Day)? / f64::from(days_in_unit))
+    }
+
+    /// The largest unit this `HumanTime` amounts to at least one of
+    fn fractional_unit(self) -> Result<jiff::Unit, crate::Error> {
+        for unit in FRACTIONAL_UNITS {
+            if self.total(unit)?.abs() >= 1.0 {
+                return Ok(unit);
+            }
+        }
+        Ok(jiff::Unit::Second)
+    }
+
+    pub(crate) fn tense(self, accuracy: Accuracy) -> Result<Tense, crate::Error> {
+        self.tense_with(accuracy, FormatOptions::default())
+    }
+
+    /// Tense of the representation with given `accuracy` and `options`, which is present for
+    /// rough spans within the now window, unless a now wording is set to tell the sign
+    pub(crate) fn tense_with(
+        self,
+        accuracy: Accuracy,
+        options: FormatOptions,
+    ) -> Result<Tense, crate::Error> {
+        let within_window = accuracy.is_rough() && self.is_within_now_window(options)?;
+        if within_window && options.now_wording.is_none() {
+            return Ok(Tense::Present);
+        }
+        self.sign_tense()
+    }
+
+    /// Tense telling only the sign of the span
+    fn sign_tense(self) -> Result<Tense, crate::Error> {
         let zero = jiff::Span::default();
-        let tense = if accuracy.is_rough() && self.0.total(jiff::Unit::Second)?.abs() < 11.0 {
-            Tense::Present
-        } else if self.0.compare(zero)? == Ordering::Greater {
+        let tense = if self.0.compare(zero)? == Ordering::Greater {
             Tense::Future
         } else if self.0.compare(zero)? == Ordering::Less {
             Tense::Past
@@ -177,54 +831,236 @@ impl HumanTime {
         Ok(tense)
     }
 
-    fn rough_period(self) -> Result<Vec<TimePeriod>, crate::Error> {
-        let period = match self.0.total(jiff::Unit::Second)?.abs() as i64 {
-            n if n > 547 * S_DAY => TimePeriod::Years(max(n / S_YEAR, 2) as i16),
+    /// Whole seconds of the span, counted from its fields rather than from the floating point
+    /// total, which falls just short of the exact count for long spans
+    fn whole_seconds(self) -> Result<i64, crate::Error> {
+        // Still errors for the calendar units the way the totals do
+        self.0.total(jiff::Unit::Second)?;
+        let span = self.0;
+        let nanos = i128::from(span.get_days()) * 86_400_000_000_000
+            + i128::from(span.get_hours()) * 3_600_000_000_000
+            + i128::from(span.get_minutes()) * 60_000_000_000
+            + i128::from(span.get_seconds()) * 1_000_000_000
+            + i128::from(span.get_milliseconds()) * 1_000_000
+            + i128::from(span.get_microseconds()) * 1_000
+            + i128::from(span.get_nanoseconds());
+        let seconds = nanos.abs() / 1_000_000_000;
+        i64::try_from(seconds).map_err(|_| {
+            crate::Error::InvalidArgument(format!("amount {} is out of range", seconds))
+        })
+    }
+
+    fn is_within_now_window(self, options: FormatOptions) -> Result<bool, crate::Error> {
+        Ok(self.0.total(jiff::Unit::Second)?.abs() < f64::from(options.now_window))
+    }
+
+    /// Period standing for a span within the now window, worded for `tense`
+    fn now_period(tense: Tense, options: FormatOptions) -> TimePeriod {
+        match options.now_wording {
+            Some(wording) => TimePeriod::Moment(wording, tense),
+            None => TimePeriod::Now,
+        }
+    }
+
+    fn rough_period(
+        self,
+        tense: Tense,
+        options: FormatOptions,
+    ) -> Result<Vec<TimePeriod>, crate::Error> {
+        if self.is_within_now_window(options)? {
+            return Ok(vec![Self::now_period(tense, options)]);
+        }
+
+        let seconds = self.whole_seconds()?;
+        let period = match seconds {
+            n if options.centuries && n > 150 * S_YEAR => {
+                TimePeriod::Centuries(amount(max(n / S_CENTURY, 2))?)
+            }
+            n if options.centuries && n > 95 * S_YEAR => TimePeriod::Centuries(1),
+            n if options.decades && n > 15 * S_YEAR => {
+                TimePeriod::Decades(amount(max(n / S_DECADE, 2))?)
+            }
+            n if options.decades && n > 9 * S_YEAR + 180 * S_DAY => TimePeriod::Decades(1),
+            n if n >= 1000 * S_YEAR => {
+                let years = n / S_YEAR / 1000 * 1000;
+                if n == years * S_YEAR {
+                    TimePeriod::Years(amount(years)?)
+                } else {
+                    TimePeriod::OverYears(amount(years)?)
+                }
+            }
+            n if n > 547 * S_DAY => TimePeriod::Years(amount(max(n / S_YEAR, 2))?),
             n if n > 345 * S_DAY => TimePeriod::Years(1),
-            n if n > 45 * S_DAY => TimePeriod::Months(max(n / S_MONTH, 2) as i32),
+            n if options.quarters && n > 136 * S_DAY => {
+                TimePeriod::YearQuarters(amount(max(n / S_QUARTER, 2))?)
+            }
+            n if options.quarters && n > 80 * S_DAY => TimePeriod::YearQuarters(1),
+            n if n > 45 * S_DAY => TimePeriod::Months(amount(max(n / S_MONTH, 2))?),
             n if n > 29 * S_DAY => TimePeriod::Months(1),
-            n if n > 10 * S_DAY + 12 * S_HOUR => TimePeriod::Weeks(max(n / S_WEEK, 2) as i32),
+            n if n > 10 * S_DAY + 12 * S_HOUR => TimePeriod::Weeks(amount(max(n / S_WEEK, 2))?),
             n if n > 6 * S_DAY + 12 * S_HOUR => TimePeriod::Weeks(1),
-            n if n > 36 * S_HOUR => TimePeriod::Days(max(n / S_DAY, 2) as i32),
+            n if n > 36 * S_HOUR => TimePeriod::Days(amount(max(n / S_DAY, 2))?),
             n if n > 22 * S_HOUR => TimePeriod::Days(1),
-            n if n > 90 * S_MINUTE => TimePeriod::Hours(max(n / S_HOUR, 2) as i32),
+            n if n > 90 * S_MINUTE => TimePeriod::Hours(amount(max(n / S_HOUR, 2))?),
             n if n > 45 * S_MINUTE => TimePeriod::Hours(1),
             n if n > 90 => TimePeriod::Minutes(max(n / S_MINUTE, 2)),
             n if n > 45 => TimePeriod::Minutes(1),
-            n if n > 10 => TimePeriod::Seconds(n),
-            0..=10 => TimePeriod::Now,
+            n if n >= 0 => TimePeriod::Seconds(n),
             _ => TimePeriod::Eternity,
         };
 
+        if options.sub_minute_buckets {
+            if let TimePeriod::Seconds(n) = period {
+                return Ok(vec![Self::sub_minute_bucket(n)]);
+            }
+        }
+
+        if let (Some(digits), TimePeriod::Seconds(_)) = (options.significant_digits, period) {
+            let nanos = self.0.total(jiff::Unit::Nanosecond)?.abs();
+            let decimal = TimePeriod::decimal(nanos, digits)?;
+            // Rounded up to the seconds of a rough minute
+            if let TimePeriod::Decimal(jiff::Unit::Second, value, _) = decimal {
+                if value >= 46.0 {
+                    return Ok(vec![TimePeriod::Minutes(1)]);
+                }
+            }
+            return Ok(vec![decimal]);
+        }
+
+        if options.idioms {
+            if let Some(idiom) = Self::idiom_period(seconds, period) {
+                return Ok(vec![idiom]);
+            }
+        }
+
         Ok(vec![period])
     }
 
-    fn precise_period(self) -> Result<Vec<TimePeriod>, crate::Error> {
+    /// "a few seconds", "half a minute" or "less than a minute" for `seconds` under a rough
+    /// minute
+    fn sub_minute_bucket(seconds: i64) -> TimePeriod {
+        match seconds {
+            n if n < 20 => TimePeriod::FewSeconds,
+            n if n < 40 => TimePeriod::Quarters(jiff::Unit::Minute, 2),
+            _ => TimePeriod::LessThanMinute,
+        }
+    }
+
+    /// Qualifier telling how the exact time relates to the rough `period`. A remainder of a
+    /// quarter of the unit or more makes it "over" or "almost", anything less "about".
+    fn qualifier(self, period: TimePeriod) -> Result<Option<Qualifier>, crate::Error> {
+        if let TimePeriod::OverYears(_) = period {
+            return Ok(None);
+        }
+        let (shown, unit_seconds) = match (period.seconds(), period.unit_seconds()) {
+            (Some(shown), Some(unit_seconds)) if unit_seconds > 1 => (shown, unit_seconds),
+            _ => return Ok(None),
+        };
+
+        let remainder = self.whole_seconds()? - shown;
+        let qualifier = match remainder * 4 {
+            0 => None,
+            r if r >= unit_seconds => Some(Qualifier::Over),
+            r if r <= -unit_seconds => Some(Qualifier::Almost),
+            _ => Some(Qualifier::About),
+        };
+        Ok(qualifier)
+    }
+
+    /// Half or quarter fraction of the unit of the rough `period`, or of the unit above it,
+    /// that `seconds` amounts to within 1/24 of that unit
+    fn idiom_period(seconds: i64, period: TimePeriod) -> Option<TimePeriod> {
+        let units: &[jiff::Unit] = match period {
+            TimePeriod::Minutes(_) => &[jiff::Unit::Hour],
+            TimePeriod::Hours(_) => &[jiff::Unit::Hour, jiff::Unit::Day],
+            TimePeriod::Days(_) => &[jiff::Unit::Day, jiff::Unit::Week],
+            TimePeriod::Weeks(_) => &[jiff::Unit::Week],
+            TimePeriod::Months(_) => &[jiff::Unit::Month, jiff::Unit::Year],
+            TimePeriod::Years(_) => &[jiff::Unit::Year],
+            _ => &[],
+        };
+
+        units.iter().find_map(|&unit| {
+            let unit_seconds = unit_seconds(unit);
+            let quarters = (seconds * 4 + unit_seconds / 2) / unit_seconds;
+            if (seconds * 4 - quarters * unit_seconds).abs() * 6 > unit_seconds {
+                return None;
+            }
+            match quarters {
+                1 | 3 if unit == jiff::Unit::Hour => Some(TimePeriod::Quarters(unit, quarters)),
+                n if n % 4 == 2 => Some(TimePeriod::Quarters(unit, quarters)),
+                _ => None,
+            }
+        })
+    }
+
+    /// Whole amounts of the units of the precise representation, largest first
+    pub(crate) fn precise_amounts(self) -> Result<Vec<(jiff::Unit, i64)>, crate::Error> {
+        let amounts = self
+            .precise_period(Tense::Present, FormatOptions::default())?
+            .into_iter()
+            .filter_map(|period| Some((period.unit()?, period.count())))
+            .collect();
+        Ok(amounts)
+    }
+
+    fn precise_period(
+        self,
+        tense: Tense,
+        options: FormatOptions,
+    ) -> Result<Vec<TimePeriod>, crate::Error> {
         let mut periods = vec![];
+        let mut reminder = self;
+
+        if options.centuries {
+            let (centuries, rest) = reminder.split_days_of(Self::DAYS_IN_YEAR * 100)?;
+            if let Some(centuries) = centuries {
+                periods.push(TimePeriod::Centuries(amount(centuries)?));
+            }
+            reminder = rest;
+        }
 
-        let (years, reminder) = self.split_years()?;
+        if options.decades {
+            let (decades, rest) = reminder.split_days_of(Self::DAYS_IN_YEAR * 10)?;
+            if let Some(decades) = decades {
+                periods.push(TimePeriod::Decades(amount(decades)?));
+            }
+            reminder = rest;
+        }
+
+        let (years, reminder) = reminder.split_years()?;
         if let Some(years) = years {
-            periods.push(TimePeriod::Years(years as i16));
+            periods.push(TimePeriod::Years(amount(years)?));
         }
 
+        let reminder = if options.quarters {
+            let (quarters, rest) = reminder.split_days_of(Self::DAYS_IN_QUARTER)?;
+            if let Some(quarters) = quarters {
+                periods.push(TimePeriod::YearQuarters(amount(quarters)?));
+            }
+            rest
+        } else {
+            reminder
+        };
+
         let (months, reminder) = reminder.split_months()?;
         if let Some(months) = months {
-            periods.push(TimePeriod::Months(months as i32));
+            periods.push(TimePeriod::Months(amount(months)?));
         }
 
         let (weeks, reminder) = reminder.split_weeks()?;
         if let Some(weeks) = weeks {
-            periods.push(TimePeriod::Weeks(weeks as i32));
+            periods.push(TimePeriod::Weeks(amount(weeks)?));
         }
 
         let (days, reminder) = reminder.split_days()?;
         if let Some(days) = days {
-            periods.push(TimePeriod::Days(days as i32));
+            periods.push(TimePeriod::Days(amount(days)?));
         }
 
         let (hours, reminder) = reminder.split_hours()?;
         if let Some(hours) = hours {
-            periods.push(TimePeriod::Hours(hours as i32));
+            periods.push(TimePeriod::Hours(amount(hours)?));
         }
 
         let (minutes, reminder) = reminder.split_minutes()?;
@@ -232,30 +1068,51 @@ impl HumanTime {
             periods.push(TimePeriod::Minutes(minutes));
         }
 
-        let (seconds, reminder) = reminder.split_seconds()?;
-        if let Some(seconds) = seconds {
-            periods.push(TimePeriod::Seconds(seconds));
-        }
+        if let Some(digits) = options.significant_digits {
+            // The rest under a minute is given as a single amount with a fraction
+            let nanos = reminder.0.total(jiff::Unit::Nanosecond)?.abs();
+            if nanos > 0.0 {
+                let decimal = TimePeriod::decimal(nanos, digits)?;
+                if let TimePeriod::Decimal(jiff::Unit::Second, value, _) = decimal {
+                    if value >= 60.0 {
+                        // Rounded up to a whole minute, which may carry into larger units
+                        let sign = if self.0.is_negative() { -1 } else { 1 };
+                        let carried = self.0.checked_sub(reminder.0)?.checked_add(sign.minute())?;
+                        return Self(carried).precise_period(tense, options);
+                    }
+                }
+                periods.push(decimal);
+            }
+        } else {
+            let (seconds, reminder) = reminder.split_seconds()?;
+            if let Some(seconds) = seconds {
+                periods.push(TimePeriod::Seconds(seconds));
+            }
 
-        let (millis, reminder) = reminder.split_milliseconds()?;
-        if let Some(millis) = millis {
-            periods.push(TimePeriod::Millis(millis));
-        }
+            let (millis, reminder) = reminder.split_milliseconds()?;
+            if let Some(millis) = millis {
+                periods.push(TimePeriod::Millis(millis));
+            }
 
-        let (micros, reminder) = reminder.split_microseconds()?;
-        if let Some(micros) = micros {
-            periods.push(TimePeriod::Micros(micros));
-        }
+            let (micros, reminder) = reminder.split_microseconds()?;
+            if let Some(micros) = micros {
+                periods.push(TimePeriod::Micros(micros));
+            }
 
-        let (nanos, reminder) = reminder.split_nanoseconds()?;
-        if let Some(nanos) = nanos {
-            periods.push(TimePeriod::Nanos(nanos));
-        }
+            let (nanos, reminder) = reminder.split_nanoseconds()?;
+            if let Some(nanos) = nanos {
+                periods.push(TimePeriod::Nanos(nanos));
+            }
 
-        debug_assert!(reminder.is_zero());
+            debug_assert!(reminder.is_zero());
+        }
 
         if periods.is_empty() {
-            periods.push(TimePeriod::Seconds(0));
+            let period = match options.now_wording {
+                Some(_) => Self::now_period(tense, options),
+                None => TimePeriod::Seconds(0),
+            };
+            periods.push(period);
         }
 
         Ok(periods)
@@ -265,35 +1122,43 @@ impl HumanTime {
     fn split_years(self) -> Result<(Option<i64>, Self), crate::Error> {
         let years = self.0.get_days() / Self::DAYS_IN_YEAR;
         let reminder = self.0.checked_sub((years * Self::DAYS_IN_YEAR).days())?;
-        Ok(Self::normalize_split(years as i64, reminder))
+        Ok(Self::normalize_split(i64::from(years), reminder))
+    }
+
+    /// Split this `HumanTime` into number of whole units of `days_in_unit` days and the
+    /// reminder
+    fn split_days_of(self, days_in_unit: i32) -> Result<(Option<i64>, Self), crate::Error> {
+        let units = self.0.get_days() / days_in_unit;
+        let reminder = self.0.checked_sub((units * days_in_unit).days())?;
+        Ok(Self::normalize_split(i64::from(units), reminder))
     }
 
     /// Split this `HumanTime` into number of whole months and the reminder
     fn split_months(self) -> Result<(Option<i64>, Self), crate::Error> {
         let months = self.0.get_days() / Self::DAYS_IN_MONTH;
         let reminder = self.0.checked_sub((months * Self::DAYS_IN_MONTH).days())?;
-        Ok(Self::normalize_split(months as i64, reminder))
+        Ok(Self::normalize_split(i64::from(months), reminder))
     }
 
     /// Split this `HumanTime` into number of whole weeks and the reminder
     fn split_weeks(self) -> Result<(Option<i64>, Self), crate::Error> {
         let weeks = self.0.get_weeks();
         let reminder = self.0.checked_sub(weeks.weeks())?;
-        Ok(Self::normalize_split(weeks as i64, reminder))
+        Ok(Self::normalize_split(i64::from(weeks), reminder))
     }
 
     /// Split this `HumanTime` into number of whole days and the reminder
     fn split_days(self) -> Result<(Option<i64>, Self), crate::Error> {
         let days = self.0.get_days();
         let reminder = self.0.checked_sub(days.days())?;
-        Ok(Self::normalize_split(days as i64, reminder))
+        Ok(Self::normalize_split(i64::from(days), reminder))
     }
 
     /// Split this `HumanTime` into number of whole hours and the reminder
     fn split_hours(self) -> Result<(Option<i64>, Self), crate::Error> {
         let hours = self.0.get_hours();
         let reminder = self.0.checked_sub(hours.hours())?;
-        Ok(Self::normalize_split(hours as i64, reminder))
+        Ok(Self::normalize_split(i64::from(hours), reminder))
     }
 
     /// Split this `HumanTime` into number of whole minutes and the reminder
@@ -367,9 +1232,21 @@ impl From<jiff::Span> for HumanTime {
     }
 }
 
+impl From<HumanTime> for jiff::Span {
+    fn from(ht: HumanTime) -> Self {
+        ht.0
+    }
+}
+
 impl From<jiff::Zoned> for HumanTime {
     fn from(dt: jiff::Zoned) -> Self {
-        Self(dt.since(&jiff::Zoned::now()).unwrap())
+        Self::from_zoned(&dt, &jiff::Zoned::now()).unwrap()
+    }
+}
+
+impl From<jiff::civil::DateTime> for HumanTime {
+    fn from(dt: jiff::civil::DateTime) -> Self {
+        Self::from_civil(dt, jiff::Zoned::now().datetime()).unwrap()
     }
 }
 
@@ -397,6 +1274,12 @@ impl Humanize for jiff::Zoned {
     }
 }
 
+impl Humanize for jiff::civil::DateTime {
+    fn humanize(&self) -> String {
+        format!("{}", HumanTime::from(*self))
+    }
+}
+
 impl Humanize for SystemTime {
     fn humanize(&self) -> String {
         HumanTime::from(*self).to_string()
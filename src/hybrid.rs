@@ -0,0 +1,126 @@
+use crate::{Accuracy, Error, FormatOptions, HumanTime};
+
+/// Formatter giving relative text, such as "3 days ago", for recent points in time and an
+/// absolute date, such as "on Mar 3" or "on Mar 3, 2023", past a cutoff
+///
+/// The cutoff is one month by default and may use calendar units, which are counted from the
+/// reference. The absolute date is rendered in the time zone of the reference, with one
+/// `jiff::fmt::strtime` template for dates in the year of the reference and another for
+/// other years.
+///
+/// ```
+/// use jiff::civil::date;
+/// use jiffy::HybridFormat;
+///
+/// let now = date(2026, 10, 18).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+/// let format = HybridFormat::new();
+///
+/// let dt = date(2026, 10, 15).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("3 days ago", format.format(&dt, &now).unwrap());
+///
+/// let dt = date(2026, 3, 3).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("on Mar 3", format.format(&dt, &now).unwrap());
+///
+/// let dt = date(2023, 3, 3).at(12, 0, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("on Mar 3, 2023", format.format(&dt, &now).unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HybridFormat {
+    cutoff: jiff::Span,
+    accuracy: Accuracy,
+    options: FormatOptions,
+    same_year: String,
+    other_year: String,
+}
+
+impl Default for HybridFormat {
+    fn default() -> Self {
+        Self {
+            cutoff: jiff::Span::new().months(1),
+            accuracy: Accuracy::Rough,
+            options: FormatOptions::default(),
+            same_year: "on %b %-d".to_string(),
+            other_year: "on %b %-d, %Y".to_string(),
+        }
+    }
+}
+
+impl HybridFormat {
+    /// Create a formatter with a cutoff of one month
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how far from the reference the text stays relative. The sign of `cutoff` is
+    /// ignored.
+    #[must_use]
+    pub fn cutoff(mut self, cutoff: jiff::Span) -> Self {
+        self.cutoff = cutoff.abs();
+        self
+    }
+
+    /// Set the accuracy of the relative text, rough by default
+    #[must_use]
+    pub fn accuracy(mut self, accuracy: Accuracy) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    /// Set the options refining the relative text
+    #[must_use]
+    pub fn options(mut self, options: FormatOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the template for absolute dates in the year of the reference, "on %b %-d" by
+    /// default
+    #[must_use]
+    pub fn same_year(mut self, template: impl Into<String>) -> Self {
+        self.same_year = template.into();
+        self
+    }
+
+    /// Set the template for absolute dates in other years, "on %b %-d, %Y" by default
+    #[must_use]
+    pub fn other_year(mut self, template: impl Into<String>) -> Self {
+        self.other_year = template.into();
+        self
+    }
+
+    /// Gives text for `dt`, relative to `reference`
+    pub fn format(&self, dt: &jiff::Zoned, reference: &jiff::Zoned) -> Result<String, Error> {
+        let past_cutoff = if dt < reference {
+            *dt < reference.checked_sub(self.cutoff)?
+        } else {
+            *dt > reference.checked_add(self.cutoff)?
+        };
+
+        if past_cutoff {
+            let dt = dt.with_time_zone(reference.time_zone().clone());
+            let template = if dt.year() == reference.year() {
+                &self.same_year
+            } else {
+                &self.other_year
+            };
+            return Ok(jiff::fmt::strtime::format(template.as_str(), &dt)?);
+        }
+
+        let ht = HumanTime::from_zoned(dt, reference)?;
+        ht.to_text_en_with(
+            self.accuracy,
+            ht.tense_with(self.accuracy, self.options)?,
+            self.options,
+        )
+    }
+
+    /// Gives text for `ts`, relative to `reference`, with absolute dates in UTC
+    pub fn format_timestamp(
+        &self,
+        ts: jiff::Timestamp,
+        reference: jiff::Timestamp,
+    ) -> Result<String, Error> {
+        let utc = jiff::tz::TimeZone::UTC;
+        self.format(&ts.to_zoned(utc.clone()), &reference.to_zoned(utc))
+    }
+}
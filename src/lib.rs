@@ -53,10 +53,40 @@
 #![warn(unused)]
 #![deny(warnings)]
 
-pub use crate::humantime::{Accuracy, HumanTime, Tense};
+pub use crate::calendar::{CalendarFormat, CalendarTime};
+pub use crate::civil::HumanDate;
+pub use crate::clock::FuzzyClock;
+pub use crate::compact::parse_compact;
+pub use crate::daypart::{DayPart, DayPartFormat};
+pub use crate::html::HtmlTime;
+pub use crate::humantime::{
+    Accuracy, Component, ComponentKind, HumanTime, NowWording, Qualifier, Tense,
+};
+pub use crate::hybrid::HybridFormat;
+pub use crate::natural::parse_zoned;
+pub use crate::offset::OffsetDifference;
+pub use crate::options::FormatOptions;
+pub use crate::parse::parse;
+pub use crate::pattern::Pattern;
+pub use crate::range::RangeFormat;
 
+mod calendar;
+mod civil;
+mod clock;
+mod compact;
+mod daypart;
 mod error;
+mod html;
 mod humantime;
+mod hybrid;
+mod natural;
+mod offset;
+mod options;
+mod parse;
+mod pattern;
+mod range;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 pub use error::Error;
 
@@ -0,0 +1,267 @@
+use jiff::civil::{Date, Time, Weekday};
+use jiff::ToSpan;
+
+use crate::parse::{Parser, Token};
+use crate::Error;
+
+/// Parse English text describing a point in time, resolving it against `reference`
+///
+/// Understands days such as "today", "tomorrow", "next Friday", "last day of the month" or
+/// "first day of next year", offsets such as "in 2 weeks" or "3 days ago", and times of day
+/// such as "at 5pm", "at 17:30", "at noon" or "midnight". A day without a time of day keeps
+/// the time of `reference`. A bare weekday refers to its next occurrence, or to the day of
+/// `reference` itself when it falls on that weekday. The result is in the time zone of
+/// `reference`.
+///
+/// ```
+/// use jiff::civil::date;
+///
+/// let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("Asia/Jakarta").unwrap();
+/// let zdt = jiffy::parse_zoned("tomorrow at 5pm", &now).unwrap();
+/// assert_eq!(date(2024, 8, 8).at(17, 0, 0, 0).in_tz("Asia/Jakarta").unwrap(), zdt);
+/// ```
+pub fn parse_zoned(text: &str, reference: &jiff::Zoned) -> Result<jiff::Zoned, Error> {
+    NaturalParser {
+        parser: Parser::new(text),
+        reference,
+    }
+    .parse()
+}
+
+struct NaturalParser<'a, 'r> {
+    parser: Parser<'a>,
+    reference: &'r jiff::Zoned,
+}
+
+impl NaturalParser<'_, '_> {
+    fn parse(mut self) -> Result<jiff::Zoned, Error> {
+        if self.parser.eat("now") {
+            self.parser.expect_end()?;
+            return Ok(self.reference.clone());
+        }
+        if self.parser.peek().is_none() {
+            return Err(Error::UnexpectedEnd {
+                expected: "a date or time",
+            });
+        }
+
+        let mut base = None;
+        let mut time = None;
+        while let Some(token) = self.parser.peek() {
+            if time.is_none() && (self.parser.eat("at") || Self::is_time(token)) {
+                time = Some(self.parse_time()?);
+            } else if base.is_none() {
+                base = Some(self.parse_day()?);
+            } else {
+                let expected = if time.is_none() {
+                    "a time of day"
+                } else {
+                    "end of input"
+                };
+                return Err(token.unexpected(expected));
+            }
+        }
+
+        let base = base.unwrap_or_else(|| self.reference.clone());
+        let time = time.unwrap_or_else(|| base.time());
+        Ok(base
+            .date()
+            .to_datetime(time)
+            .to_zoned(base.time_zone().clone())?)
+    }
+
+    /// Parse a day, or an offset from the reference time
+    fn parse_day(&mut self) -> Result<jiff::Zoned, Error> {
+        if let Some(zdt) = self.parse_offset()? {
+            return Ok(zdt);
+        }
+
+        let today = self.reference.date();
+        let token = self.parser.take("a date or time")?;
+        let date = if token.is("today") {
+            today
+        } else if token.is("tomorrow") {
+            today.tomorrow()?
+        } else if token.is("yesterday") {
+            today.yesterday()?
+        } else if token.is("first") {
+            self.parser.expect("day")?;
+            self.parser.expect("of")?;
+            self.parse_day_of(today, true)?
+        } else if token.is("last") && self.parser.eat("day") {
+            self.parser.expect("of")?;
+            self.parse_day_of(today, false)?
+        } else if token.is("next") || token.is("last") || token.is("this") {
+            let direction = match token.text.to_ascii_lowercase().as_str() {
+                "next" => 1,
+                "last" => -1,
+                _ => 0,
+            };
+            self.parse_relative_day(today, direction)?
+        } else if let Some(weekday) = Self::weekday(token) {
+            Self::upcoming(today, weekday)?
+        } else {
+            return Err(token.unexpected("a date or time"));
+        };
+
+        let time = self.reference.time();
+        Ok(date
+            .to_datetime(time)
+            .to_zoned(self.reference.time_zone().clone())?)
+    }
+
+    /// Parse "in <amounts>" or "<amounts> ago" into the reference time moved by the amounts.
+    /// Leaves the parser untouched when the text is not an offset.
+    fn parse_offset(&mut self) -> Result<Option<jiff::Zoned>, Error> {
+        let start = self.parser.next;
+        let span = if self.parser.eat("in") {
+            self.parser.parse_amounts()?.to_calendar_span()?
+        } else {
+            match self.parser.parse_amounts() {
+                Ok(amounts) if self.parser.eat("ago") => amounts.to_calendar_span()?.negate(),
+                _ => {
+                    self.parser.next = start;
+                    return Ok(None);
+                }
+            }
+        };
+        Ok(Some(self.reference.checked_add(span)?))
+    }
+
+    /// Parse the rest of "first day of ..." or "last day of ..."
+    fn parse_day_of(&mut self, today: Date, first: bool) -> Result<Date, Error> {
+        let offset = if self.parser.eat("next") {
+            1
+        } else if self.parser.eat("last") {
+            -1
+        } else {
+            let _ = self.parser.eat("the") || self.parser.eat("this");
+            0
+        };
+
+        let token = self.parser.take("`month` or `year`")?;
+        let date = if token.is("month") {
+            let month = today.first_of_month().checked_add(offset.months())?;
+            if first {
+                month
+            } else {
+                month.last_of_month()
+            }
+        } else if token.is("year") {
+            let year = today.first_of_year().checked_add(offset.years())?;
+            if first {
+                year
+            } else {
+                year.last_of_year()
+            }
+        } else {
+            return Err(token.unexpected("`month` or `year`"));
+        };
+        Ok(date)
+    }
+
+    /// Parse the rest of "next ...", "last ..." or "this ..." moving `direction` weekdays,
+    /// weeks, months or years from `today`
+    fn parse_relative_day(&mut self, today: Date, direction: i32) -> Result<Date, Error> {
+        let expected = "a weekday, `week`, `month` or `year`";
+        let token = self.parser.take(expected)?;
+        let date = if let Some(weekday) = Self::weekday(token) {
+            match direction {
+                0 => Self::upcoming(today, weekday)?,
+                n => today.nth_weekday(n, weekday)?,
+            }
+        } else if token.is("week") {
+            today.checked_add(direction.weeks())?
+        } else if token.is("month") {
+            today.checked_add(direction.months())?
+        } else if token.is("year") {
+            today.checked_add(direction.years())?
+        } else {
+            return Err(token.unexpected(expected));
+        };
+        Ok(date)
+    }
+
+    /// Parse a time of day such as "5pm", "5:30 pm", "17:30", "noon" or "midnight"
+    fn parse_time(&mut self) -> Result<Time, Error> {
+        let expected = "a time of day";
+        let token = self.parser.take(expected)?;
+        if token.is("noon") {
+            return Ok(Time::constant(12, 0, 0, 0));
+        }
+        if token.is("midnight") {
+            return Ok(Time::midnight());
+        }
+
+        let text = token.text.to_ascii_lowercase();
+        let (clock, mut meridiem) = match text.len().checked_sub(2) {
+            Some(i) if text.ends_with("am") || text.ends_with("pm") => {
+                (&text[..i], Some(&text[i..] == "pm"))
+            }
+            _ => (text.as_str(), None),
+        };
+        if meridiem.is_none() {
+            if self.parser.eat("am") {
+                meridiem = Some(false);
+            } else if self.parser.eat("pm") {
+                meridiem = Some(true);
+            }
+        }
+
+        let (hour, minute) = match clock.split_once(':') {
+            Some((hour, minute)) if minute.len() == 2 => (hour, Some(minute)),
+            Some(_) => return Err(token.unexpected(expected)),
+            None => (clock, None),
+        };
+        let number = |text: &str| text.parse::<i8>().map_err(|_| token.unexpected(expected));
+        let hour = number(hour)?;
+        let minute = minute.map(number).transpose()?;
+
+        let hour = match (meridiem, minute) {
+            (Some(pm), _) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+            (Some(_), _) => return Err(token.unexpected("an hour between 1 and 12")),
+            (None, Some(_)) => hour,
+            (None, None) => {
+                return Err(Error::Ambiguous {
+                    token: token.text.to_string(),
+                    position: token.position,
+                    hint: "add am or pm, or use a 24-hour time such as 17:00",
+                })
+            }
+        };
+        Time::new(hour, minute.unwrap_or(0), 0, 0)
+            .map_err(|_| token.unexpected("a valid time of day"))
+    }
+
+    /// Whether `token` starts a time of day without a leading "at"
+    fn is_time(token: Token<'_>) -> bool {
+        let text = token.text.to_ascii_lowercase();
+        text == "noon"
+            || text == "midnight"
+            || (text.starts_with(|c: char| c.is_ascii_digit())
+                && (text.contains(':') || text.ends_with("am") || text.ends_with("pm")))
+    }
+
+    /// The next day falling on `weekday`, including `today`
+    fn upcoming(today: Date, weekday: Weekday) -> Result<Date, Error> {
+        if today.weekday() == weekday {
+            Ok(today)
+        } else {
+            Ok(today.nth_weekday(1, weekday)?)
+        }
+    }
+
+    fn weekday(token: Token<'_>) -> Option<Weekday> {
+        let weekday = match token.text.to_ascii_lowercase().as_str() {
+            "monday" | "mon" => Weekday::Monday,
+            "tuesday" | "tue" => Weekday::Tuesday,
+            "wednesday" | "wed" => Weekday::Wednesday,
+            "thursday" | "thu" => Weekday::Thursday,
+            "friday" | "fri" => Weekday::Friday,
+            "saturday" | "sat" => Weekday::Saturday,
+            "sunday" | "sun" => Weekday::Sunday,
+            _ => return None,
+        };
+        Some(weekday)
+    }
+}
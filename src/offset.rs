@@ -0,0 +1,80 @@
+use std::fmt;
+
+use crate::{Accuracy, Error, HumanTime, Tense};
+
+/// Difference between the offsets of two time zones at a point in time, such as
+/// "9 hours ahead of you", "5 hours and 30 minutes behind you" or "same time as you"
+///
+/// The amount is the precise text of `HumanTime`.
+///
+/// ```
+/// use jiff::tz::TimeZone;
+/// use jiffy::OffsetDifference;
+///
+/// let ts: jiff::Timestamp = "2026-01-15T12:00:00Z".parse().unwrap();
+/// let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+/// let diff = OffsetDifference::new(&tokyo, &TimeZone::UTC, ts);
+/// assert_eq!("Tokyo is 9 hours ahead of you", format!("Tokyo is {}", diff));
+///
+/// let kolkata = TimeZone::get("Asia/Kolkata").unwrap();
+/// let diff = OffsetDifference::new(&kolkata, &tokyo, ts).reference_name("Tokyo");
+/// assert_eq!("3 hours and 30 minutes behind Tokyo", diff.to_text_en().unwrap());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OffsetDifference {
+    seconds: i32,
+    reference_name: String,
+}
+
+impl OffsetDifference {
+    /// Create the difference of the offset of `tz` from the offset of `reference` at `ts`
+    pub fn new(
+        tz: &jiff::tz::TimeZone,
+        reference: &jiff::tz::TimeZone,
+        ts: jiff::Timestamp,
+    ) -> Self {
+        let (offset, _, _) = tz.to_offset(ts);
+        let (reference_offset, _, _) = reference.to_offset(ts);
+        Self {
+            seconds: offset.seconds() - reference_offset.seconds(),
+            reference_name: "you".to_string(),
+        }
+    }
+
+    /// Set how the text names the reference time zone, "you" by default
+    #[must_use]
+    pub fn reference_name(mut self, name: impl Into<String>) -> Self {
+        self.reference_name = name.into();
+        self
+    }
+
+    /// Gives the difference as a span of hours, minutes and seconds, negative when the time
+    /// zone is behind the reference
+    pub fn span(&self) -> jiff::Span {
+        let seconds = i64::from(self.seconds);
+        jiff::Span::new()
+            .hours(seconds / 3600)
+            .minutes(seconds % 3600 / 60)
+            .seconds(seconds % 60)
+    }
+
+    /// Gives English text representation
+    pub fn to_text_en(&self) -> Result<String, Error> {
+        if self.seconds == 0 {
+            return Ok(format!("same time as {}", self.reference_name));
+        }
+        let amount = HumanTime::from(self.span()).to_text_en(Accuracy::Precise, Tense::Present)?;
+        let direction = if self.seconds > 0 {
+            "ahead of"
+        } else {
+            "behind"
+        };
+        Ok(format!("{} {} {}", amount, direction, self.reference_name))
+    }
+}
+
+impl fmt::Display for OffsetDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.to_text_en().map_err(|_| fmt::Error)?)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::NowWording;
+
+/// Options refining the text representation of `HumanTime`
+///
+/// ```
+/// use jiff::ToSpan;
+/// use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+///
+/// let ht = HumanTime::from(90.minutes());
+/// let options = FormatOptions::new().idioms(true);
+/// assert_eq!("an hour and a half", ht.to_text_en_with(Accuracy::Rough, Tense::Present, options).unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct FormatOptions {
+    pub(crate) idioms: bool,
+    pub(crate) qualifiers: bool,
+    pub(crate) quarters: bool,
+    pub(crate) decades: bool,
+    pub(crate) centuries: bool,
+    pub(crate) now_window: u32,
+    pub(crate) now_wording: Option<NowWording>,
+    pub(crate) sub_minute_buckets: bool,
+    pub(crate) significant_digits: Option<u8>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            idioms: false,
+            qualifiers: false,
+            quarters: false,
+            decades: false,
+            centuries: false,
+            now_window: 11,
+            now_wording: None,
+            sub_minute_buckets: false,
+            significant_digits: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Create options that give the default representation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render half and quarter fractions of the rough unit as idioms, such as
+    /// "half an hour" or "a year and a half"
+    #[must_use]
+    pub fn idioms(mut self, yes: bool) -> Self {
+        self.idioms = yes;
+        self
+    }
+
+    /// Prefix the rough representation with "about", "almost" or "over" when it is not
+    /// exact, depending on how far off it is
+    #[must_use]
+    pub fn qualifiers(mut self, yes: bool) -> Self {
+        self.qualifiers = yes;
+        self
+    }
+
+    /// Count in quarters of a year, 91 days each, such as "2 quarters ago"
+    #[must_use]
+    pub fn quarters(mut self, yes: bool) -> Self {
+        self.quarters = yes;
+        self
+    }
+
+    /// Count in decades, such as "in a decade"
+    #[must_use]
+    pub fn decades(mut self, yes: bool) -> Self {
+        self.decades = yes;
+        self
+    }
+
+    /// Count in centuries, such as "3 centuries ago"
+    #[must_use]
+    pub fn centuries(mut self, yes: bool) -> Self {
+        self.centuries = yes;
+        self
+    }
+
+    /// Set how many seconds a span must be shorter than for the rough representation to
+    /// call it "now", 11 by default. With zero no span is "now", not even an empty one, which
+    /// reads "0 seconds".
+    #[must_use]
+    pub fn now_window(mut self, seconds: u32) -> Self {
+        self.now_window = seconds;
+        self
+    }
+
+    /// Set the wording for spans within the now window, such as "just now" or
+    /// "moments from now". It is also used for empty spans in the precise representation,
+    /// which are "0 seconds" otherwise.
+    #[must_use]
+    pub fn now_wording(mut self, wording: NowWording) -> Self {
+        self.now_wording = Some(wording);
+        self
+    }
+
+    /// Render rough spans under a minute as "a few seconds", "half a minute" or
+    /// "less than a minute" instead of the exact count of seconds
+    #[must_use]
+    pub fn sub_minute_buckets(mut self, yes: bool) -> Self {
+        self.sub_minute_buckets = yes;
+        self
+    }
+
+    /// Give the part of a span under a minute as a single amount rounded to `digits`
+    /// significant digits, in the largest unit of a second or below it amounts to at least
+    /// one of, such as "1.25 seconds" or "250 ms". The rough representation does so for
+    /// spans under a rough minute past the now window, unless sub-minute buckets are used,
+    /// so spans shorter than a second need a window of zero.
+    ///
+    /// ```
+    /// use jiff::ToSpan;
+    /// use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+    ///
+    /// let options = FormatOptions::new().significant_digits(3).now_window(0);
+    /// let ht = HumanTime::from(1.seconds().milliseconds(250).microseconds(3));
+    /// assert_eq!("1.25 seconds", ht.to_text_en_with(Accuracy::Precise, Tense::Present, options).unwrap());
+    /// let ht = HumanTime::from(250.milliseconds().nanoseconds(12));
+    /// assert_eq!("250 ms", ht.to_text_en_with(Accuracy::Rough, Tense::Present, options).unwrap());
+    /// ```
+    ///
+    /// Formatting fails for zero digits.
+    #[must_use]
+    pub fn significant_digits(mut self, digits: u8) -> Self {
+        self.significant_digits = Some(digits);
+        self
+    }
+}
@@ -0,0 +1,502 @@
+use std::str::FromStr;
+
+use crate::humantime::{unit_seconds, S_DAY, S_HOUR, S_MINUTE};
+use crate::{Error, HumanTime};
+
+/// Parse English text, as given by `HumanTime::to_text_en`, back into `HumanTime`
+///
+/// Both rough and precise representations are accepted, in any tense. Text in the past
+/// tense gives a negative span.
+///
+/// Precise text gives back a span of the same length as the original one. Rough text gives
+/// back the amount it shows, which is off from the original by at most half of that amount,
+/// or by less than 11 seconds for "now". Decimal counts of an hour or shorter, such as
+/// "1.25 seconds", are accepted as given by `FormatOptions::significant_digits`.
+///
+/// ```
+/// use jiff::ToSpan;
+/// use jiffy::HumanTime;
+///
+/// let ht = jiffy::parse("2 hours ago").unwrap();
+/// assert_eq!(HumanTime::from((-2).hours()), ht);
+///
+/// let ht: HumanTime = "in 1 month and 15 days".parse().unwrap();
+/// assert_eq!(HumanTime::from(45.days()), ht);
+/// ```
+pub fn parse(text: &str) -> Result<HumanTime, Error> {
+    Parser::new(text).parse()
+}
+
+impl FromStr for HumanTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Token<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) position: usize,
+}
+
+impl Token<'_> {
+    pub(crate) fn is(&self, word: &str) -> bool {
+        self.text.eq_ignore_ascii_case(word)
+    }
+
+    pub(crate) fn unexpected(&self, expected: &'static str) -> Error {
+        Error::UnexpectedToken {
+            token: self.text.to_string(),
+            position: self.position,
+            expected,
+        }
+    }
+}
+
+/// Amounts of the units that make up the parsed span
+#[derive(Debug, Default)]
+pub(crate) struct Amounts {
+    years: i64,
+    months: i64,
+    weeks: i64,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    millis: i64,
+    micros: i64,
+    nanos: i64,
+}
+
+impl Amounts {
+    pub(crate) fn add(&mut self, unit: jiff::Unit, count: i64) -> Option<()> {
+        let field = match unit {
+            jiff::Unit::Nanosecond => &mut self.nanos,
+            jiff::Unit::Microsecond => &mut self.micros,
+            jiff::Unit::Millisecond => &mut self.millis,
+            jiff::Unit::Second => &mut self.seconds,
+            jiff::Unit::Minute => &mut self.minutes,
+            jiff::Unit::Hour => &mut self.hours,
+            jiff::Unit::Day => &mut self.days,
+            jiff::Unit::Week => &mut self.weeks,
+            jiff::Unit::Month => &mut self.months,
+            jiff::Unit::Year => &mut self.years,
+        };
+        *field = field.checked_add(count)?;
+        Some(())
+    }
+
+    /// Add a number of quarters of `unit`, balanced into days, hours, minutes and seconds
+    fn add_quarters(&mut self, unit: jiff::Unit, quarters: i64) -> Option<()> {
+        if unit < jiff::Unit::Minute {
+            return None;
+        }
+        let seconds = quarters.checked_mul(unit_seconds(unit))? / 4;
+        self.add(jiff::Unit::Day, seconds / S_DAY)?;
+        self.add(jiff::Unit::Hour, seconds % S_DAY / S_HOUR)?;
+        self.add(jiff::Unit::Minute, seconds % S_HOUR / S_MINUTE)?;
+        self.add(jiff::Unit::Second, seconds % S_MINUTE)
+    }
+
+    /// Add a number of nanoseconds, balanced into hours and smaller units
+    pub(crate) fn add_nanos(&mut self, nanos: i64) -> Option<()> {
+        self.add(jiff::Unit::Hour, nanos / 3_600_000_000_000)?;
+        self.add(jiff::Unit::Minute, nanos / 60_000_000_000 % 60)?;
+        self.add(jiff::Unit::Second, nanos / 1_000_000_000 % 60)?;
+        self.add(jiff::Unit::Millisecond, nanos / 1_000_000 % 1_000)?;
+        self.add(jiff::Unit::Microsecond, nanos / 1_000 % 1_000)?;
+        self.add(jiff::Unit::Nanosecond, nanos % 1_000)
+    }
+
+    /// Span with weeks, months and years counted in days, the way `HumanTime` counts them
+    pub(crate) fn to_span(&self) -> Result<jiff::Span, Error> {
+        let days = [
+            (self.years, 365),
+            (self.months, 30),
+            (self.weeks, 7),
+            (self.days, 1),
+        ]
+        .iter()
+        .try_fold(0i64, |acc, &(count, days)| {
+            count
+                .checked_mul(days)
+                .and_then(|days| acc.checked_add(days))
+        })
+        .ok_or_else(|| Error::InvalidArgument("amount of days is out of range".to_string()))?;
+        self.with_time_units(jiff::Span::new().try_days(days)?)
+    }
+
+    /// Span keeping weeks, months and years as calendar units
+    pub(crate) fn to_calendar_span(&self) -> Result<jiff::Span, Error> {
+        let span = jiff::Span::new()
+            .try_years(self.years)?
+            .try_months(self.months)?
+            .try_weeks(self.weeks)?
+            .try_days(self.days)?;
+        self.with_time_units(span)
+    }
+
+    /// Add the amounts of hours and smaller units to `span`
+    fn with_time_units(&self, span: jiff::Span) -> Result<jiff::Span, Error> {
+        let span = span
+            .try_hours(self.hours)?
+            .try_minutes(self.minutes)?
+            .try_seconds(self.seconds)?
+            .try_milliseconds(self.millis)?
+            .try_microseconds(self.micros)?
+            .try_nanoseconds(self.nanos)?;
+        Ok(span)
+    }
+}
+
+pub(crate) struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pub(crate) next: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        let mut tokens = vec![];
+        let mut start = None;
+        for (position, c) in text.char_indices() {
+            if c.is_whitespace() || c == ',' {
+                if let Some(start) = start.take() {
+                    tokens.push(Token {
+                        text: &text[start..position],
+                        position: start,
+                    });
+                }
+                if c == ',' {
+                    tokens.push(Token {
+                        text: &text[position..position + 1],
+                        position,
+                    });
+                }
+            } else if start.is_none() {
+                start = Some(position);
+            }
+        }
+        if let Some(start) = start {
+            tokens.push(Token {
+                text: &text[start..],
+                position: start,
+            });
+        }
+
+        Self { tokens, next: 0 }
+    }
+
+    fn parse(mut self) -> Result<HumanTime, Error> {
+        // Wordings of `NowWording`
+        let moments: [&[&str]; 4] = [
+            &["just", "now"],
+            &["a", "moment", "ago"],
+            &["in", "a", "moment"],
+            &["moments", "from", "now"],
+        ];
+        if let Some(words) = moments.iter().find(|words| self.peek_is(words)) {
+            self.next += words.len();
+            self.expect_end()?;
+            return Ok(HumanTime::now());
+        }
+
+        let future = self.eat("in");
+        if self.eat("now") {
+            // `to_text_en` puts "now" in the requested tense too
+            if !future {
+                self.eat("ago");
+            }
+            self.expect_end()?;
+            return Ok(HumanTime::now());
+        }
+
+        // Qualifiers only tell how exact the text is, the amount stays the same
+        let _ = self.eat("about") || self.eat("almost") || self.eat("over");
+
+        let amounts = match self.parse_bucket() {
+            Some(amounts) => amounts,
+            None => self.parse_amounts()?,
+        };
+
+        let past = match self.peek() {
+            Some(token) if token.is("ago") && future => {
+                return Err(token.unexpected("end of input"))
+            }
+            Some(token) if token.is("ago") => {
+                self.next += 1;
+                true
+            }
+            _ => false,
+        };
+        self.expect_end()?;
+
+        let span = amounts.to_span()?;
+        Ok(HumanTime::from(if past { span.negate() } else { span }))
+    }
+
+    /// Parse a sub-minute bucket, "a few seconds" standing for 10 seconds and
+    /// "less than a minute" for 45 seconds
+    fn parse_bucket(&mut self) -> Option<Amounts> {
+        let buckets: [(&[&str], i64); 2] = [
+            (&["a", "few", "seconds"], 10),
+            (&["less", "than", "a", "minute"], 45),
+        ];
+        let &(words, seconds) = buckets.iter().find(|(words, _)| self.peek_is(words))?;
+        self.next += words.len();
+
+        let mut amounts = Amounts::default();
+        amounts.add(jiff::Unit::Second, seconds)?;
+        Some(amounts)
+    }
+
+    /// Parse a list of amounts, such as "1 month and 15 days"
+    pub(crate) fn parse_amounts(&mut self) -> Result<Amounts, Error> {
+        let mut amounts = Amounts::default();
+        loop {
+            self.parse_item(&mut amounts)?;
+            if !(self.eat(",") || self.eat("and")) {
+                return Ok(amounts);
+            }
+        }
+    }
+
+    /// Parse a single amount of a unit, such as "2 hours", "a day" or "half an hour"
+    fn parse_item(&mut self, amounts: &mut Amounts) -> Result<(), Error> {
+        let token = self.take("a count")?;
+
+        if token.is("half") {
+            self.expect_article()?;
+            let (unit, factor, _) = self.parse_unit()?;
+            return Self::add_quarters(amounts, token, unit, 2 * factor);
+        }
+
+        if token.is("three") {
+            self.expect("quarters")?;
+            self.expect("of")?;
+            self.expect_article()?;
+            let (unit, factor, _) = self.parse_unit()?;
+            return Self::add_quarters(amounts, token, unit, 3 * factor);
+        }
+
+        if token.is("a") || token.is("an") {
+            // "a quarter of an hour", unlike "a quarter" of a year
+            if self.peek_is(&["quarter", "of"]) {
+                self.next += 2;
+                self.expect_article()?;
+                let (unit, factor, _) = self.parse_unit()?;
+                return Self::add_quarters(amounts, token, unit, factor);
+            }
+
+            let (unit, factor, unit_token) = self.parse_unit()?;
+            if self.eat_and_a_half() {
+                return Self::add_quarters(amounts, unit_token, unit, 6 * factor);
+            }
+            return Self::add(amounts, unit_token, unit, factor);
+        }
+
+        let count_text = self.count_text(token);
+        if count_text.contains('.') {
+            let (unit, _, unit_token) = self.parse_unit()?;
+            if unit > jiff::Unit::Hour {
+                return Err(unit_token.unexpected("a unit of an hour or shorter"));
+            }
+            let nanos =
+                decimal_nanos(&count_text, unit).ok_or_else(|| token.unexpected("a count"))?;
+            return amounts
+                .add_nanos(nanos)
+                .ok_or_else(|| token.unexpected("a smaller count"));
+        }
+        // Only unsigned counts, the tense tells the sign
+        if !count_text.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(token.unexpected("a count"));
+        }
+        let count = count_text
+            .parse::<i64>()
+            .map_err(|_| token.unexpected("a count"))?;
+        if self.eat_and_a_half() {
+            let (unit, factor, unit_token) = self.parse_unit()?;
+            let quarters = count
+                .checked_mul(4)
+                .and_then(|q| q.checked_add(2))
+                .and_then(|q| q.checked_mul(factor))
+                .ok_or_else(|| token.unexpected("a smaller count"))?;
+            return Self::add_quarters(amounts, unit_token, unit, quarters);
+        }
+        let (unit, factor, unit_token) = self.parse_unit()?;
+        let count = count
+            .checked_mul(factor)
+            .ok_or_else(|| token.unexpected("a smaller count"))?;
+        Self::add(amounts, unit_token, unit, count)
+    }
+
+    /// Text of the count starting with `token`, without the commas separating thousands as in
+    /// "9,000"
+    fn count_text(&mut self, token: Token<'a>) -> String {
+        let mut text = token.text.to_string();
+        let mut end = token.position + token.text.len();
+        while let (Some(comma), Some(group)) =
+            (self.tokens.get(self.next), self.tokens.get(self.next + 1))
+        {
+            let is_group = comma.text == ","
+                && comma.position == end
+                && group.position == end + 1
+                && group.text.len() == 3
+                && group.text.bytes().all(|b| b.is_ascii_digit());
+            if !is_group || !token.text.bytes().all(|b| b.is_ascii_digit()) {
+                break;
+            }
+            text.push_str(group.text);
+            end = group.position + group.text.len();
+            self.next += 2;
+        }
+        text
+    }
+
+    /// Parse a unit, giving the unit of jiff it is counted in and how many of those it is
+    fn parse_unit(&mut self) -> Result<(jiff::Unit, i64, Token<'a>), Error> {
+        let token = self.take("a unit")?;
+        let (unit, factor) = match token.text.to_ascii_lowercase().as_str() {
+            "quarter" | "quarters" => (jiff::Unit::Day, 91),
+            "decade" | "decades" => (jiff::Unit::Year, 10),
+            "century" | "centuries" => (jiff::Unit::Year, 100),
+            text => (
+                Self::unit(text).ok_or_else(|| token.unexpected("a unit"))?,
+                1,
+            ),
+        };
+        Ok((unit, factor, token))
+    }
+
+    fn unit(text: &str) -> Option<jiff::Unit> {
+        let unit = match text {
+            "ns" => jiff::Unit::Nanosecond,
+            "µs" | "us" => jiff::Unit::Microsecond,
+            "ms" => jiff::Unit::Millisecond,
+            "second" | "seconds" => jiff::Unit::Second,
+            "minute" | "minutes" => jiff::Unit::Minute,
+            "hour" | "hours" => jiff::Unit::Hour,
+            "day" | "days" => jiff::Unit::Day,
+            "week" | "weeks" => jiff::Unit::Week,
+            "month" | "months" => jiff::Unit::Month,
+            "year" | "years" => jiff::Unit::Year,
+            _ => return None,
+        };
+        Some(unit)
+    }
+
+    fn add(
+        amounts: &mut Amounts,
+        token: Token<'_>,
+        unit: jiff::Unit,
+        count: i64,
+    ) -> Result<(), Error> {
+        amounts
+            .add(unit, count)
+            .ok_or_else(|| token.unexpected("a smaller amount"))
+    }
+
+    fn add_quarters(
+        amounts: &mut Amounts,
+        token: Token<'_>,
+        unit: jiff::Unit,
+        quarters: i64,
+    ) -> Result<(), Error> {
+        amounts
+            .add_quarters(unit, quarters)
+            .ok_or_else(|| token.unexpected("a unit of a minute or longer"))
+    }
+
+    fn eat_and_a_half(&mut self) -> bool {
+        let words = ["and", "a", "half"];
+        let matches = self.peek_is(&words);
+        if matches {
+            self.next += words.len();
+        }
+        matches
+    }
+
+    /// Whether the next tokens are `words`
+    fn peek_is(&self, words: &[&str]) -> bool {
+        words.iter().enumerate().all(|(i, word)| {
+            self.tokens
+                .get(self.next + i)
+                .is_some_and(|token| token.is(word))
+        })
+    }
+
+    pub(crate) fn expect_article(&mut self) -> Result<(), Error> {
+        if self.eat("a") || self.eat("an") {
+            Ok(())
+        } else {
+            Err(self.unexpected("`a` or `an`"))
+        }
+    }
+
+    pub(crate) fn expect(&mut self, word: &'static str) -> Result<(), Error> {
+        if self.eat(word) {
+            Ok(())
+        } else {
+            Err(self.unexpected(word))
+        }
+    }
+
+    pub(crate) fn expect_end(&self) -> Result<(), Error> {
+        match self.peek() {
+            Some(token) => Err(token.unexpected("end of input")),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn eat(&mut self, word: &str) -> bool {
+        match self.peek() {
+            Some(token) if token.is(word) => {
+                self.next += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn take(&mut self, expected: &'static str) -> Result<Token<'a>, Error> {
+        let token = self.peek().ok_or(Error::UnexpectedEnd { expected })?;
+        self.next += 1;
+        Ok(token)
+    }
+
+    pub(crate) fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.next).copied()
+    }
+
+    pub(crate) fn unexpected(&self, expected: &'static str) -> Error {
+        match self.peek() {
+            Some(token) => token.unexpected(expected),
+            None => Error::UnexpectedEnd { expected },
+        }
+    }
+}
+
+/// Nanoseconds in `count` of `unit`, an hour or shorter, when `count` is a decimal number
+/// such as "1.25". Digits past nanoseconds are dropped.
+pub(crate) fn decimal_nanos(count: &str, unit: jiff::Unit) -> Option<i64> {
+    let (whole, fraction) = count.split_once('.')?;
+    let is_digits = |text: &str| !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+    let unit_nanos: i64 = match unit {
+        jiff::Unit::Nanosecond => 1,
+        jiff::Unit::Microsecond => 1_000,
+        jiff::Unit::Millisecond => 1_000_000,
+        unit => unit_seconds(unit) * 1_000_000_000,
+    };
+
+    let mut nanos = whole.parse::<i64>().ok()?.checked_mul(unit_nanos)?;
+    let mut scale = unit_nanos;
+    for digit in fraction.bytes() {
+        scale /= 10;
+        nanos = nanos.checked_add(i64::from(digit - b'0') * scale)?;
+    }
+    Some(nanos)
+}
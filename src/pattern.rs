@@ -0,0 +1,313 @@
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::humantime::unit_seconds;
+use crate::{Error, HumanTime};
+
+/// Compiled pattern for custom text representation of `HumanTime`
+///
+/// The pattern is literal text with directives in braces. `{years}`, `{months}`, `{weeks}`,
+/// `{days}`, `{hours}`, `{minutes}`, `{seconds}`, `{millis}`, `{micros}` and `{nanos}` give the
+/// amount of the unit. The span is split over the units in the pattern from the largest one
+/// down, with the unit lengths of the precise representation, and whatever is smaller than
+/// the smallest unit is dropped. `{sign}` gives `-` for negative spans. A width after a
+/// colon pads the amount with spaces, or with zeros when it starts with `0`, as in
+/// `{minutes:02}`. `{{` and `}}` give literal braces.
+///
+/// The units are also available as strftime-like directives of two letters: `%yy`, `%mo`,
+/// `%ww`, `%dd`, `%hh`, `%mi`, `%ss`, `%ms`, `%us` and `%ns`, with `%+` for the sign. A width
+/// goes between `%` and the name, as in `%02mi`. `%%` gives a literal percent sign.
+///
+/// ```
+/// use jiff::ToSpan;
+/// use jiffy::{HumanTime, Pattern};
+///
+/// let pattern: Pattern = "{hours}h {minutes:02}m".parse().unwrap();
+/// let ht = HumanTime::from(1.days().hours(2).minutes(5));
+/// assert_eq!("26h 05m", pattern.format(ht).unwrap());
+///
+/// let pattern: Pattern = "%yy years, %mo months, %dd days".parse().unwrap();
+/// let ht = HumanTime::from(428.days());
+/// assert_eq!("1 years, 2 months, 3 days", pattern.format(ht).unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern {
+    items: Vec<Item>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Item {
+    Literal(String),
+    Sign,
+    Amount {
+        unit: jiff::Unit,
+        width: usize,
+        zero_pad: bool,
+    },
+}
+
+// Units a pattern can refer to, largest first
+const UNITS: [(&str, jiff::Unit); 10] = [
+    ("years", jiff::Unit::Year),
+    ("months", jiff::Unit::Month),
+    ("weeks", jiff::Unit::Week),
+    ("days", jiff::Unit::Day),
+    ("hours", jiff::Unit::Hour),
+    ("minutes", jiff::Unit::Minute),
+    ("seconds", jiff::Unit::Second),
+    ("millis", jiff::Unit::Millisecond),
+    ("micros", jiff::Unit::Microsecond),
+    ("nanos", jiff::Unit::Nanosecond),
+];
+
+// Units of the strftime-like directives, largest first
+const PERCENT_UNITS: [(&str, jiff::Unit); 10] = [
+    ("yy", jiff::Unit::Year),
+    ("mo", jiff::Unit::Month),
+    ("ww", jiff::Unit::Week),
+    ("dd", jiff::Unit::Day),
+    ("hh", jiff::Unit::Hour),
+    ("mi", jiff::Unit::Minute),
+    ("ss", jiff::Unit::Second),
+    ("ms", jiff::Unit::Millisecond),
+    ("us", jiff::Unit::Microsecond),
+    ("ns", jiff::Unit::Nanosecond),
+];
+
+impl Pattern {
+    /// Compile `pattern`
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        let mut items = vec![];
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices().peekable();
+
+        while let Some((position, c)) = chars.next() {
+            match c {
+                '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek().map(|&(_, c)| c) == Some('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let end = pattern[position..]
+                        .find('}')
+                        .map(|i| position + i)
+                        .ok_or(Error::UnexpectedEnd { expected: "`}`" })?;
+                    while chars.peek().is_some_and(|&(i, _)| i <= end) {
+                        chars.next();
+                    }
+                    if !literal.is_empty() {
+                        items.push(Item::Literal(std::mem::take(&mut literal)));
+                    }
+                    items.push(Self::directive(&pattern[position + 1..end], position + 1)?);
+                }
+                '%' => {
+                    let (item, len) = Self::percent_directive(&pattern[position + 1..], position)?;
+                    for _ in 0..len {
+                        chars.next();
+                    }
+                    match item {
+                        Some(item) => {
+                            if !literal.is_empty() {
+                                items.push(Item::Literal(std::mem::take(&mut literal)));
+                            }
+                            items.push(item);
+                        }
+                        None => literal.push('%'),
+                    }
+                }
+                '}' => {
+                    return Err(Error::UnexpectedToken {
+                        token: "}".to_string(),
+                        position,
+                        expected: "`}}` for a literal brace",
+                    })
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+
+        Ok(Self { items })
+    }
+
+    /// Compile the text between braces, which starts at `position` of the pattern
+    fn directive(text: &str, position: usize) -> Result<Item, Error> {
+        let (name, spec) = match text.split_once(':') {
+            Some((name, spec)) => (name, Some(spec)),
+            None => (text, None),
+        };
+        let unexpected =
+            |token: &str, position: usize, expected: &'static str| Error::UnexpectedToken {
+                token: token.to_string(),
+                position,
+                expected,
+            };
+
+        if name == "sign" {
+            return match spec {
+                None => Ok(Item::Sign),
+                Some(spec) => Err(unexpected(spec, position + name.len() + 1, "`}`")),
+            };
+        }
+
+        let unit = UNITS
+            .iter()
+            .find(|(unit_name, _)| *unit_name == name)
+            .map(|&(_, unit)| unit)
+            .ok_or_else(|| unexpected(name, position, "a unit name or `sign`"))?;
+
+        let (width, zero_pad) = match spec {
+            None => (0, false),
+            Some(spec) => {
+                let width = spec
+                    .parse::<u8>()
+                    .map_err(|_| unexpected(spec, position + name.len() + 1, "a width"))?;
+                (usize::from(width), spec.starts_with('0'))
+            }
+        };
+
+        Ok(Item::Amount {
+            unit,
+            width,
+            zero_pad,
+        })
+    }
+
+    /// Compile the strftime-like directive in `text`, which follows the `%` at `position` of
+    /// the pattern. Gives the item, or `None` for a literal percent sign, and the number of
+    /// characters of the directive after the `%`.
+    fn percent_directive(text: &str, position: usize) -> Result<(Option<Item>, usize), Error> {
+        if text.starts_with('%') {
+            return Ok((None, 1));
+        }
+        if text.starts_with('+') {
+            return Ok((Some(Item::Sign), 1));
+        }
+        if text.starts_with('-') {
+            // The strftime flag leaving out padding, which durations have no use for
+            return Err(Error::UnexpectedToken {
+                token: "-".to_string(),
+                position: position + 1,
+                expected: "a width or unit directive, without the `-` flag",
+            });
+        }
+
+        let digits = text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (width, zero_pad) = match &text[..digits] {
+            "" => (0, false),
+            spec => {
+                let width = spec.parse::<u8>().map_err(|_| Error::UnexpectedToken {
+                    token: spec.to_string(),
+                    position: position + 1,
+                    expected: "a width",
+                })?;
+                (usize::from(width), spec.starts_with('0'))
+            }
+        };
+
+        let rest = &text[digits..];
+        let (name, unit) = PERCENT_UNITS
+            .iter()
+            .find(|(name, _)| rest.starts_with(name))
+            .ok_or_else(|| match rest.chars().next() {
+                Some(_) => Error::UnexpectedToken {
+                    token: rest.chars().take(2).collect(),
+                    position: position + 1 + digits,
+                    expected: "a unit directive, `%+` or `%%`",
+                },
+                None => Error::UnexpectedEnd {
+                    expected: "a unit directive, `%+` or `%%`",
+                },
+            })?;
+
+        let item = Item::Amount {
+            unit: *unit,
+            width,
+            zero_pad,
+        };
+        Ok((Some(item), digits + name.len()))
+    }
+
+    /// Gives text representation of `ht` following the pattern
+    pub fn format(&self, ht: HumanTime) -> Result<String, Error> {
+        let amounts = self.amounts(ht)?;
+        let span = jiff::Span::from(ht);
+
+        let mut text = String::new();
+        for item in &self.items {
+            match *item {
+                Item::Literal(ref literal) => text.push_str(literal),
+                Item::Sign if span.is_negative() => text.push('-'),
+                Item::Sign => {}
+                Item::Amount {
+                    unit,
+                    width,
+                    zero_pad,
+                } => {
+                    let amount = amounts
+                        .iter()
+                        .find(|(u, _)| *u == unit)
+                        .map_or(0, |&(_, amount)| amount);
+                    // Writing to a `String` cannot fail
+                    let _ = if zero_pad {
+                        write!(text, "{:0width$}", amount, width = width)
+                    } else {
+                        write!(text, "{:width$}", amount, width = width)
+                    };
+                }
+            }
+        }
+        Ok(text)
+    }
+
+    /// Amounts of the units in the pattern, with the whole span split over them from the
+    /// largest unit down
+    fn amounts(&self, ht: HumanTime) -> Result<Vec<(jiff::Unit, i64)>, Error> {
+        let mut rest: i128 = ht
+            .precise_amounts()?
+            .into_iter()
+            .map(|(unit, amount)| i128::from(amount) * unit_nanos(unit))
+            .sum();
+        let mut amounts = vec![];
+
+        for &(_, unit) in UNITS.iter().filter(|&&(_, unit)| self.uses(unit)) {
+            let nanos = unit_nanos(unit);
+            let amount = i64::try_from(rest / nanos)
+                .map_err(|_| Error::InvalidArgument("amount is out of range".to_string()))?;
+            amounts.push((unit, amount));
+            rest %= nanos;
+        }
+        Ok(amounts)
+    }
+
+    fn uses(&self, unit: jiff::Unit) -> bool {
+        self.items
+            .iter()
+            .any(|item| matches!(item, Item::Amount { unit: u, .. } if *u == unit))
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// Number of nanoseconds in `unit`, using the lengths of the precise representation
+fn unit_nanos(unit: jiff::Unit) -> i128 {
+    match unit {
+        jiff::Unit::Nanosecond => 1,
+        jiff::Unit::Microsecond => 1_000,
+        jiff::Unit::Millisecond => 1_000_000,
+        unit => i128::from(unit_seconds(unit)) * 1_000_000_000,
+    }
+}
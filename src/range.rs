@@ -0,0 +1,203 @@
+use jiff::fmt::strtime;
+
+use crate::calendar::weekday_name_en;
+use crate::{Accuracy, Error, HumanTime, Tense};
+
+/// Formatter for the range between two points in time, leaving out the fields they share,
+/// such as "Mar 3 – 5", "Mar 30 – Apr 2, 2026" or "3 – 5 PM"
+///
+/// Ranges starting and ending at midnight are shown as dates, other ranges within a day as
+/// times of day and the rest as dates with times. The end is shown in the time zone of the
+/// start.
+///
+/// ```
+/// use jiff::civil::date;
+/// use jiffy::RangeFormat;
+///
+/// let start = date(2026, 3, 30).at(0, 0, 0, 0).in_tz("UTC").unwrap();
+/// let end = date(2026, 4, 2).at(0, 0, 0, 0).in_tz("UTC").unwrap();
+/// assert_eq!("Mar 30 – Apr 2, 2026", RangeFormat::new().format(&start, &end).unwrap());
+///
+/// let start = date(2026, 3, 3).at(9, 0, 0, 0).in_tz("UTC").unwrap();
+/// let end = date(2026, 3, 3).at(17, 0, 0, 0).in_tz("UTC").unwrap();
+/// let format = RangeFormat::new().weekday(true).separator(" to ");
+/// assert_eq!("Tuesday 9 AM to 5 PM", format.format(&start, &end).unwrap());
+///
+/// let format = RangeFormat::new().length(true);
+/// assert_eq!("9 AM – 5 PM (8 hours)", format.format(&start, &end).unwrap());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RangeFormat {
+    separator: String,
+    year: bool,
+    weekday: bool,
+    hour24: bool,
+    length: bool,
+}
+
+impl Default for RangeFormat {
+    fn default() -> Self {
+        Self {
+            separator: " – ".to_string(),
+            year: true,
+            weekday: false,
+            hour24: false,
+            length: false,
+        }
+    }
+}
+
+impl RangeFormat {
+    /// Create a formatter with an en dash between start and end
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text between start and end, " – " by default
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Show the year of dates, on by default. Years are always shown when start and end
+    /// fall in different years.
+    #[must_use]
+    pub fn year(mut self, year: bool) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// Show the weekday before ranges within a day, such as "Tuesday 9 AM – 5 PM"
+    #[must_use]
+    pub fn weekday(mut self, weekday: bool) -> Self {
+        self.weekday = weekday;
+        self
+    }
+
+    /// Use the 24-hour clock for times of day
+    #[must_use]
+    pub fn hour24(mut self, hour24: bool) -> Self {
+        self.hour24 = hour24;
+        self
+    }
+
+    /// Append the precise length of the range, such as " (2 hours)", counting calendar days
+    /// in the time zone of the start
+    #[must_use]
+    pub fn length(mut self, length: bool) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Gives text for the range from `start` to `end`
+    pub fn format(&self, start: &jiff::Zoned, end: &jiff::Zoned) -> Result<String, Error> {
+        if end < start {
+            return Err(Error::InvalidArgument(format!(
+                "range ends at {} before it starts at {}",
+                end, start
+            )));
+        }
+        let end = end.with_time_zone(start.time_zone().clone());
+        let midnight = jiff::civil::Time::midnight();
+
+        let mut text = if start.time() == midnight && end.time() == midnight {
+            self.dates(start, &end)?
+        } else if start.date() == end.date() {
+            self.times(start, &end)?
+        } else {
+            let show_year = self.year || start.year() != end.year();
+            format!(
+                "{}, {}{}{}, {}",
+                Self::date(start, show_year)?,
+                self.time(start, true)?,
+                self.separator,
+                Self::date(&end, show_year)?,
+                self.time(&end, true)?
+            )
+        };
+
+        if self.length {
+            let ht = HumanTime::from_zoned(&end, start)?;
+            text = format!(
+                "{} ({})",
+                text,
+                ht.to_text_en(Accuracy::Precise, Tense::Present)?
+            );
+        }
+        Ok(text)
+    }
+
+    /// "Mar 3 – 5", "Mar 30 – Apr 2" or "Dec 30, 2025 – Jan 2, 2026", with the year last when
+    /// it is shared
+    fn dates(&self, start: &jiff::Zoned, end: &jiff::Zoned) -> Result<String, Error> {
+        if start.year() != end.year() {
+            return Ok(format!(
+                "{}{}{}",
+                Self::date(start, true)?,
+                self.separator,
+                Self::date(end, true)?
+            ));
+        }
+
+        let text = if start.date() == end.date() {
+            strtime::format("%b %-d", start)?
+        } else if start.month() == end.month() {
+            format!(
+                "{}{}{}",
+                strtime::format("%b %-d", start)?,
+                self.separator,
+                end.day()
+            )
+        } else {
+            format!(
+                "{}{}{}",
+                strtime::format("%b %-d", start)?,
+                self.separator,
+                strtime::format("%b %-d", end)?
+            )
+        };
+        if self.year {
+            Ok(format!("{}, {}", text, start.year()))
+        } else {
+            Ok(text)
+        }
+    }
+
+    /// "3 – 5 PM" or "11 AM – 1 PM", with the weekday first when asked for
+    fn times(&self, start: &jiff::Zoned, end: &jiff::Zoned) -> Result<String, Error> {
+        let same_meridiem = (start.hour() < 12) == (end.hour() < 12);
+        let text = format!(
+            "{}{}{}",
+            self.time(start, !same_meridiem)?,
+            self.separator,
+            self.time(end, true)?
+        );
+        if self.weekday {
+            Ok(format!("{} {}", weekday_name_en(start.weekday()), text))
+        } else {
+            Ok(text)
+        }
+    }
+
+    /// "Mar 3" or "Mar 3, 2026"
+    fn date(dt: &jiff::Zoned, year: bool) -> Result<String, Error> {
+        let format = if year { "%b %-d, %Y" } else { "%b %-d" };
+        Ok(strtime::format(format, dt)?)
+    }
+
+    /// "9 AM", "9:30 AM" or "09:30", leaving out "AM" and "PM" unless `meridiem`
+    fn time(&self, dt: &jiff::Zoned, meridiem: bool) -> Result<String, Error> {
+        let format = if self.hour24 {
+            "%H:%M"
+        } else {
+            match (dt.minute(), meridiem) {
+                (0, true) => "%-I %p",
+                (0, false) => "%-I",
+                (_, true) => "%-I:%M %p",
+                (_, false) => "%-I:%M",
+            }
+        };
+        Ok(strtime::format(format, dt)?)
+    }
+}
@@ -0,0 +1,53 @@
+//! Serialization helpers for `HumanTime`, to be used with `#[serde(with = "...")]`
+//!
+//! ```
+//! use jiff::ToSpan;
+//! use jiffy::HumanTime;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Task {
+//!     #[serde(with = "jiffy::serde::text")]
+//!     due: HumanTime,
+//!     #[serde(with = "jiffy::serde::span")]
+//!     took: HumanTime,
+//! }
+//!
+//! let task = Task {
+//!     due: HumanTime::from(2.days().hours(3)),
+//!     took: HumanTime::from((-90).minutes()),
+//! };
+//! let json = serde_json::to_string(&task).unwrap();
+//! assert_eq!(r#"{"due":"in 2 days and 3 hours","took":"-PT90M"}"#, json);
+//! ```
+
+/// Serialize `HumanTime` as its underlying `jiff::Span`, the same as its own implementation
+pub mod span {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::HumanTime;
+
+    pub fn serialize<S: Serializer>(ht: &HumanTime, serializer: S) -> Result<S::Ok, S::Error> {
+        jiff::Span::from(*ht).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HumanTime, D::Error> {
+        jiff::Span::deserialize(deserializer).map(HumanTime::from)
+    }
+}
+
+/// Serialize `HumanTime` as its precise English text, such as "in 2 days and 3 hours".
+/// Any text `jiffy::parse` understands is accepted when deserializing.
+pub mod text {
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::HumanTime;
+
+    pub fn serialize<S: Serializer>(ht: &HumanTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#}", ht))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HumanTime, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        crate::parse(&text).map_err(de::Error::custom)
+    }
+}
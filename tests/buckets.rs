@@ -0,0 +1,92 @@
+macro_rules! bucket_test  {
+    ($($name:ident: $duration:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from($duration);
+            let options = FormatOptions::new().sub_minute_buckets(true);
+            let text = ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?;
+            assert_eq!($text, text);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod rough {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    // test_name: Duration, "Rough text"
+    bucket_test! {
+        few_seconds: (-11).seconds(), "a few seconds ago",
+        few_seconds_edge: (-19).seconds(), "a few seconds ago",
+        half_a_minute: (-20).seconds(), "half a minute ago",
+        half_a_minute_edge: (-39).seconds(), "half a minute ago",
+        less_than_a_minute: (-40).seconds(), "less than a minute ago",
+        less_than_a_minute_edge: (-45).seconds(), "less than a minute ago",
+        minute: (-46).seconds(), "a minute ago",
+        minutes: (-3).minutes(), "3 minutes ago",
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    #[test]
+    fn off_by_default() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-37).seconds());
+        assert_eq!(
+            "37 seconds ago",
+            ht.to_text_en(Accuracy::Rough, Tense::Past)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn with_now_window() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-5).seconds());
+        let options = FormatOptions::new().sub_minute_buckets(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+        assert_eq!("now", text);
+        let options = options.now_window(0);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?;
+        assert_eq!("a few seconds ago", text);
+        Ok(())
+    }
+
+    #[test]
+    fn qualifier() -> anyhow::Result<()> {
+        let ht = HumanTime::from(25.seconds());
+        let options = FormatOptions::new()
+            .sub_minute_buckets(true)
+            .qualifiers(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Future, options)?;
+        assert_eq!("in about half a minute", text);
+        Ok(())
+    }
+
+    #[test]
+    fn precise_unaffected() -> anyhow::Result<()> {
+        let ht = HumanTime::from(37.seconds());
+        let options = FormatOptions::new().sub_minute_buckets(true);
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Present, options)?;
+        assert_eq!("37 seconds", text);
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> anyhow::Result<()> {
+        assert_eq!(
+            HumanTime::from((-10).seconds()),
+            "a few seconds ago".parse()?
+        );
+        assert_eq!(HumanTime::from(30.seconds()), "in half a minute".parse()?);
+        assert_eq!(
+            HumanTime::from((-45).seconds()),
+            "less than a minute ago".parse()?
+        );
+        Ok(())
+    }
+}
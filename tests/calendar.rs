@@ -0,0 +1,138 @@
+macro_rules! calendar_test  {
+    ($($name:ident: $date:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            // Wednesday
+            let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("UTC")?;
+            let dt = $date.at(10, 0, 0, 0).in_tz("UTC")?;
+            assert_eq!($text, CalendarTime::new(&dt, &now).to_text_en()?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod phrases {
+    use jiff::civil::date;
+    use jiffy::CalendarTime;
+
+    // test_name: Date, "Text"
+    calendar_test! {
+        today: date(2024, 8, 7), "today",
+        tomorrow: date(2024, 8, 8), "tomorrow",
+        yesterday: date(2024, 8, 6), "yesterday",
+        next_weekday: date(2024, 8, 12), "next Monday",
+        last_weekday: date(2024, 8, 1), "last Thursday",
+        next_week: date(2024, 8, 14), "next week",
+        last_week: date(2024, 7, 31), "last week",
+        weeks_in_month: date(2024, 8, 21), "in 2 weeks",
+        end_of_month: date(2024, 8, 30), "in 3 weeks",
+        next_month: date(2024, 9, 2), "next month",
+        last_month: date(2024, 7, 1), "last month",
+        months: date(2024, 11, 30), "in 3 months",
+        months_ago: date(2024, 3, 1), "5 months ago",
+        next_year: date(2025, 2, 1), "next year",
+        last_year: date(2023, 12, 31), "last year",
+        years: date(2029, 1, 1), "in 5 years",
+        years_ago: date(2010, 8, 7), "14 years ago",
+    }
+}
+
+#[cfg(test)]
+mod zones {
+    use jiff::civil::date;
+    use jiffy::CalendarTime;
+
+    #[test]
+    fn late_evening_is_yesterday() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(8, 0, 0, 0).in_tz("UTC")?;
+        let dt = now.checked_sub(jiff::Span::new().hours(23))?;
+        assert_eq!("yesterday", CalendarTime::new(&dt, &now).to_text_en()?);
+        Ok(())
+    }
+
+    #[test]
+    fn date_in_reference_zone() -> anyhow::Result<()> {
+        // 2024-08-07 in New York, but already 2024-08-08 in Tokyo
+        let dt = date(2024, 8, 7).at(20, 0, 0, 0).in_tz("America/New_York")?;
+        let now = date(2024, 8, 8).at(20, 0, 0, 0).in_tz("Asia/Tokyo")?;
+        assert_eq!("today", CalendarTime::new(&dt, &now).to_text_en()?);
+        let now = now.with_time_zone(jiff::tz::TimeZone::get("America/New_York")?);
+        assert_eq!("yesterday", format!("{}", CalendarTime::new(&dt, &now)));
+        Ok(())
+    }
+}
+
+macro_rules! calendar_format_test  {
+    ($($name:ident: $datetime:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            // Wednesday
+            let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("UTC")?;
+            let dt = $datetime.in_tz("UTC")?;
+            assert_eq!($text, CalendarFormat::new().format(&dt, &now)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod format {
+    use jiff::civil::date;
+    use jiffy::CalendarFormat;
+
+    // test_name: Date and time, "Text"
+    calendar_format_test! {
+        same_day: date(2024, 8, 7).at(15, 5, 0, 0), "Today at 3:05 PM",
+        next_day: date(2024, 8, 8).at(0, 30, 0, 0), "Tomorrow at 12:30 AM",
+        next_week: date(2024, 8, 13).at(9, 0, 0, 0), "Tuesday at 9:00 AM",
+        last_day: date(2024, 8, 6).at(23, 59, 0, 0), "Yesterday at 11:59 PM",
+        last_week: date(2024, 8, 5).at(14, 30, 0, 0), "Last Monday at 2:30 PM",
+        same_else_future: date(2024, 8, 14).at(9, 0, 0, 0), "08/14/2024",
+        same_else_past: date(2024, 7, 31).at(9, 0, 0, 0), "07/31/2024",
+    }
+}
+
+#[cfg(test)]
+mod templates {
+    use jiff::civil::date;
+    use jiffy::{CalendarFormat, Error};
+
+    #[test]
+    fn overridden() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("UTC")?;
+        let calendar = CalendarFormat::new()
+            .same_day("%H:%M")
+            .last_week("%a %H:%M")
+            .same_else("%d %b %Y");
+        let dt = date(2024, 8, 7).at(15, 5, 0, 0).in_tz("UTC")?;
+        assert_eq!("15:05", calendar.format(&dt, &now)?);
+        let dt = date(2024, 8, 3).at(8, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("Sat 08:00", calendar.format(&dt, &now)?);
+        let dt = date(2023, 1, 2).at(8, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("02 Jan 2023", calendar.format(&dt, &now)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reference_zone() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("Europe/Paris")?;
+        let dt = date(2024, 8, 7).at(22, 30, 0, 0).in_tz("UTC")?;
+        assert_eq!(
+            "Tomorrow at 12:30 AM",
+            CalendarFormat::new().format(&dt, &now)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_template() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("UTC")?;
+        let calendar = CalendarFormat::new().same_day("%H %");
+        assert!(matches!(
+            calendar.format(&now, &now),
+            Err(Error::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+}
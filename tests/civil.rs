@@ -0,0 +1,60 @@
+macro_rules! date_test  {
+    ($($name:ident: $date:expr, $rough:expr, $precise:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let hd = HumanDate::new($date, date(2024, 8, 7));
+            assert_eq!($rough, format!("{}", hd));
+            assert_eq!($precise, format!("{:#}", hd));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod dates {
+    use jiff::civil::date;
+    use jiffy::HumanDate;
+
+    // test_name: Date, "Rough text", "Precise text"
+    date_test! {
+        today: date(2024, 8, 7), "today", "today",
+        tomorrow: date(2024, 8, 8), "in a day", "in 1 day",
+        days: date(2024, 8, 10), "in 3 days", "in 3 days",
+        days_ago: date(2024, 8, 1), "6 days ago", "6 days ago",
+        weeks: date(2024, 8, 21), "in 2 weeks", "in 14 days",
+        months_ago: date(2024, 6, 7), "2 months ago", "2 months and 1 day ago",
+        years: date(2026, 8, 7), "in 2 years", "in 2 years",
+    }
+}
+
+#[cfg(test)]
+mod datetimes {
+    use jiff::civil::date;
+    use jiffy::{Accuracy, HumanDate, HumanTime, Tense};
+
+    #[test]
+    fn from_civil() -> anyhow::Result<()> {
+        let reference = date(2024, 8, 7).at(10, 0, 0, 0);
+        let ht = HumanTime::from_civil(date(2024, 8, 7).at(7, 30, 0, 0), reference)?;
+        assert_eq!("2 hours ago", format!("{}", ht));
+        assert_eq!(
+            "2 hours and 30 minutes",
+            ht.to_text_en(Accuracy::Precise, Tense::Present)?
+        );
+
+        let ht = HumanTime::from_civil(date(2024, 8, 10).at(10, 0, 0, 0), reference)?;
+        assert_eq!("in 3 days", format!("{}", ht));
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_reference() -> anyhow::Result<()> {
+        let today = date(2024, 8, 7);
+        let hd = HumanDate::new(today, today);
+        assert_eq!("today", hd.to_string());
+        assert_eq!("today", hd.to_text_en(Accuracy::Precise)?);
+
+        let hd = HumanDate::new(today.yesterday()?, today);
+        assert_eq!("a day ago", hd.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+macro_rules! clock_test  {
+    ($($name:ident: $time:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            assert_eq!($text, FuzzyClock::new().to_text_en($time));
+        })+
+    }
+}
+
+#[cfg(test)]
+mod spoken {
+    use jiff::civil::time;
+    use jiffy::FuzzyClock;
+
+    // test_name: Time, "Text"
+    clock_test! {
+        midnight: time(0, 0, 0, 0), "midnight",
+        noon: time(12, 0, 0, 0), "noon",
+        o_clock: time(17, 0, 0, 0), "five o'clock",
+        five_past: time(9, 5, 0, 0), "five past nine",
+        ten_past_midnight: time(0, 10, 0, 0), "ten past midnight",
+        quarter_past: time(17, 15, 0, 0), "quarter past five",
+        twenty_past: time(8, 20, 0, 0), "twenty past eight",
+        twenty_five_past: time(8, 25, 0, 0), "twenty-five past eight",
+        half_past: time(15, 30, 0, 0), "half past three",
+        twenty_five_to: time(15, 35, 0, 0), "twenty-five to four",
+        quarter_to_noon: time(11, 45, 0, 0), "quarter to noon",
+        ten_to: time(17, 50, 0, 0), "ten to six",
+        rounded_down: time(17, 17, 29, 0), "quarter past five",
+        rounded_up: time(17, 17, 30, 0), "twenty past five",
+        rounded_to_midnight: time(23, 58, 0, 0), "midnight",
+        rounded_to_hour: time(6, 57, 40, 0), "seven o'clock",
+    }
+}
+
+#[cfg(test)]
+mod numeric {
+    use jiff::civil::time;
+    use jiffy::{FuzzyClock, Humanize};
+
+    #[test]
+    fn exact() {
+        let clock = FuzzyClock::new().exact(true);
+        assert_eq!("half past three", clock.to_text_en(time(15, 30, 0, 0)));
+        assert_eq!("3:31 PM", clock.to_text_en(time(15, 31, 0, 0)));
+        assert_eq!("3:30 PM", clock.to_text_en(time(15, 30, 1, 0)));
+        assert_eq!("12:07 AM", clock.to_text_en(time(0, 7, 0, 0)));
+        assert_eq!("12:07 PM", clock.to_text_en(time(12, 7, 0, 0)));
+    }
+
+    #[test]
+    fn hour24() {
+        let clock = FuzzyClock::new().exact(true).hour24(true);
+        assert_eq!("noon", clock.to_text_en(time(12, 0, 0, 0)));
+        assert_eq!("00:07", clock.to_text_en(time(0, 7, 0, 0)));
+        assert_eq!("17:32", clock.to_numeric_en(time(17, 32, 59, 0)));
+    }
+
+    #[test]
+    fn humanize() {
+        assert_eq!("quarter to six", time(17, 44, 0, 0).humanize());
+    }
+}
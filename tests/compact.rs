@@ -0,0 +1,131 @@
+macro_rules! compact_test  {
+    ($($name:ident: $text:expr, $duration:expr, $formatted:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let span = jiffy::parse_compact($text)?;
+            assert_eq!($duration, span);
+            let formatted = HumanTime::from(span).to_text_compact()?;
+            assert_eq!($formatted, formatted);
+            assert_eq!(span, jiffy::parse_compact(&formatted)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod text {
+    use jiff::ToSpan;
+    use jiffy::HumanTime;
+
+    // test_name: "Text", Expected duration, "Formatted text"
+    compact_test! {
+        zero: "0s", jiff::Span::default(), "0s",
+        seconds: "90s", 90.seconds(), "90s",
+        hours_minutes: "1h30m", 1.hours().minutes(30), "1h 30m",
+        with_spaces: "2d 4h", 2.days().hours(4), "2d 4h",
+        spaces_within: " 2 d  4 h ", 2.days().hours(4), "2d 4h",
+        negative: "-1h30m", (-1).hours().minutes(30), "-1h 30m",
+        positive: "+15m", 15.minutes(), "15m",
+        aliases: "5min 3sec", 5.minutes().seconds(3), "5m 3s",
+        subsecond: "1s250ms3us12ns",
+            1.seconds().milliseconds(250).microseconds(3).nanoseconds(12),
+            "1s 250ms 3µs 12ns",
+        micro_sign: "3µs", 3.microseconds(), "3µs",
+        weeks: "2w", 14.days(), "14d",
+        months: "1mo15d", 45.days(), "1mo 15d",
+        years: "1y 2mo 3d", 428.days(), "1y 2mo 3d",
+        negative_years: "-2y", (-730).days(), "-2y",
+        decimal_hours: "1.5h", 1.hours().minutes(30), "1h 30m",
+        decimal_seconds: "0.5s", 500.milliseconds(), "500ms",
+        negative_decimal: "-1.5h", (-1).hours().minutes(30), "-1h 30m",
+        decimal_millis: "2.25ms 1h", 1.hours().milliseconds(2).microseconds(250),
+            "1h 2ms 250µs",
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use jiffy::Error;
+
+    #[test]
+    fn unknown_unit() {
+        let err = jiffy::parse_compact("1h 30x").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "x".to_string(),
+                position: 5,
+                expected: "a unit",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn missing_number() {
+        let err = jiffy::parse_compact("1h m").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "m".to_string(),
+                position: 3,
+                expected: "a number",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn missing_unit() {
+        let err = jiffy::parse_compact("1h30").unwrap_err();
+        assert_eq!(Error::UnexpectedEnd { expected: "a unit" }, err);
+    }
+
+    #[test]
+    fn too_large() {
+        let err = jiffy::parse_compact("99999999999999999999s").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "99999999999999999999s".to_string(),
+                position: 0,
+                expected: "a smaller number",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let err = jiffy::parse_compact("-").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedEnd {
+                expected: "a duration"
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn decimal_days() {
+        let err = jiffy::parse_compact("1.5d").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "d".to_string(),
+                position: 3,
+                expected: "a unit of an hour or shorter",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn missing_fraction() {
+        let err = jiffy::parse_compact("1.s").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "1.s".to_string(),
+                position: 0,
+                expected: "a number",
+            },
+            err
+        );
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod duration {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, ComponentKind, FormatOptions, HumanTime, Qualifier, Tense};
+
+    type Parts = (Option<jiff::Unit>, f64, Option<Qualifier>);
+
+    fn components(
+        ht: HumanTime,
+        accuracy: Accuracy,
+        options: FormatOptions,
+    ) -> anyhow::Result<Vec<Parts>> {
+        let components = ht
+            .components(accuracy, Tense::Present, options)?
+            .map(|c| (c.unit(), c.value(), c.qualifier()))
+            .collect();
+        Ok(components)
+    }
+
+    #[test]
+    fn now() -> anyhow::Result<()> {
+        let ht = HumanTime::from(5.seconds());
+        let rough = components(ht, Accuracy::Rough, FormatOptions::new())?;
+        assert_eq!(vec![(None, 0.0, None)], rough);
+        let kinds = ht
+            .components(Accuracy::Rough, Tense::Present, FormatOptions::new())?
+            .map(|c| c.kind())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![ComponentKind::Now], kinds);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_precise() -> anyhow::Result<()> {
+        let precise = components(HumanTime::now(), Accuracy::Precise, FormatOptions::new())?;
+        assert_eq!(vec![(Some(jiff::Unit::Second), 0.0, None)], precise);
+        Ok(())
+    }
+
+    #[test]
+    fn rough() -> anyhow::Result<()> {
+        let ht = HumanTime::from(45.days());
+        let rough = components(ht, Accuracy::Rough, FormatOptions::new())?;
+        assert_eq!(vec![(Some(jiff::Unit::Month), 1.0, None)], rough);
+        Ok(())
+    }
+
+    #[test]
+    fn rough_with_options() -> anyhow::Result<()> {
+        let ht = HumanTime::from(92.minutes());
+        let options = FormatOptions::new().idioms(true).qualifiers(true);
+        let rough = components(ht, Accuracy::Rough, options)?;
+        assert_eq!(
+            vec![(Some(jiff::Unit::Hour), 1.5, Some(Qualifier::About))],
+            rough
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn precise() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-45).days().hours(3).milliseconds(250));
+        let precise = components(ht, Accuracy::Precise, FormatOptions::new().qualifiers(true))?;
+        assert_eq!(
+            vec![
+                (Some(jiff::Unit::Month), 1.0, None),
+                (Some(jiff::Unit::Day), 15.0, None),
+                (Some(jiff::Unit::Hour), 3.0, None),
+                (Some(jiff::Unit::Millisecond), 250.0, None),
+            ],
+            precise
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod component {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    #[test]
+    fn tense() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-3).days().hours(2));
+        let tenses = ht
+            .components(Accuracy::Precise, Tense::Past, FormatOptions::new())?
+            .map(|c| c.tense())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Tense::Past, Tense::Past], tenses);
+        Ok(())
+    }
+
+    #[test]
+    fn text() -> anyhow::Result<()> {
+        let ht = HumanTime::from(1.days().hours(2));
+        let rough = ht
+            .components(Accuracy::Rough, Tense::Future, FormatOptions::new())?
+            .map(|c| c.to_text_en(Accuracy::Rough))
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a day"], rough);
+        let precise = ht
+            .components(Accuracy::Precise, Tense::Future, FormatOptions::new())?
+            .map(|c| c.to_text_en(Accuracy::Precise))
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["1 day", "2 hours"], precise);
+        Ok(())
+    }
+}
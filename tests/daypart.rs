@@ -0,0 +1,148 @@
+macro_rules! daypart_test  {
+    ($($name:ident: $datetime:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            // Wednesday afternoon
+            let now = date(2024, 8, 7).at(14, 0, 0, 0).in_tz("UTC")?;
+            let dt = $datetime.in_tz("UTC")?;
+            assert_eq!($text, DayPartFormat::new().format(&dt, &now)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod phrases {
+    use jiff::civil::date;
+    use jiffy::DayPartFormat;
+
+    // test_name: Date and time, "Text"
+    daypart_test! {
+        this_morning: date(2024, 8, 7).at(8, 0, 0, 0), "this morning",
+        this_afternoon: date(2024, 8, 7).at(12, 0, 0, 0), "this afternoon",
+        this_evening: date(2024, 8, 7).at(18, 0, 0, 0), "this evening",
+        tonight: date(2024, 8, 7).at(22, 0, 0, 0), "tonight",
+        tonight_after_midnight: date(2024, 8, 8).at(2, 0, 0, 0), "tonight",
+        last_night: date(2024, 8, 6).at(23, 0, 0, 0), "last night",
+        last_night_after_midnight: date(2024, 8, 7).at(4, 59, 0, 0), "last night",
+        yesterday_morning: date(2024, 8, 6).at(9, 0, 0, 0), "yesterday morning",
+        yesterday_evening: date(2024, 8, 6).at(20, 0, 0, 0), "yesterday evening",
+        tomorrow_morning: date(2024, 8, 8).at(5, 0, 0, 0), "tomorrow morning",
+        tomorrow_night: date(2024, 8, 8).at(21, 0, 0, 0), "tomorrow night",
+        further: date(2024, 8, 5).at(9, 0, 0, 0), "last Monday",
+    }
+}
+
+#[cfg(test)]
+mod early_reference {
+    use jiff::civil::date;
+    use jiffy::DayPartFormat;
+
+    fn format(dt: jiff::civil::DateTime) -> anyhow::Result<String> {
+        // Wednesday, in the early hours of the night of Tuesday
+        let now = date(2024, 8, 7).at(3, 0, 0, 0).in_tz("UTC")?;
+        Ok(DayPartFormat::new().format(&dt.in_tz("UTC")?, &now)?)
+    }
+
+    #[test]
+    fn same_morning() -> anyhow::Result<()> {
+        assert_eq!("this morning", format(date(2024, 8, 7).at(8, 0, 0, 0))?);
+        Ok(())
+    }
+
+    #[test]
+    fn same_night() -> anyhow::Result<()> {
+        assert_eq!("tonight", format(date(2024, 8, 7).at(1, 0, 0, 0))?);
+        assert_eq!("tonight", format(date(2024, 8, 6).at(23, 0, 0, 0))?);
+        // Later in the small hours
+        assert_eq!("tonight", format(date(2024, 8, 7).at(4, 0, 0, 0))?);
+        Ok(())
+    }
+
+    #[test]
+    fn night_before() -> anyhow::Result<()> {
+        assert_eq!("last night", format(date(2024, 8, 6).at(2, 0, 0, 0))?);
+        assert_eq!("last night", format(date(2024, 8, 5).at(22, 0, 0, 0))?);
+        assert_eq!(
+            "yesterday evening",
+            format(date(2024, 8, 6).at(18, 0, 0, 0))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn coming_night() -> anyhow::Result<()> {
+        assert_eq!("tonight", format(date(2024, 8, 7).at(22, 0, 0, 0))?);
+        assert_eq!("tonight", format(date(2024, 8, 8).at(2, 0, 0, 0))?);
+        Ok(())
+    }
+
+    #[test]
+    fn further() -> anyhow::Result<()> {
+        assert_eq!("tomorrow morning", format(date(2024, 8, 8).at(8, 0, 0, 0))?);
+        assert_eq!("next Friday", format(date(2024, 8, 9).at(8, 0, 0, 0))?);
+        // The early hours of Friday belong to the night of Thursday
+        assert_eq!("tomorrow night", format(date(2024, 8, 9).at(2, 0, 0, 0))?);
+        // The early hours of Monday belong to the night of Sunday
+        assert_eq!("last Sunday", format(date(2024, 8, 5).at(2, 0, 0, 0))?);
+        Ok(())
+    }
+
+    #[test]
+    fn small_hours_after_midnight() -> anyhow::Result<()> {
+        let now = date(2026, 10, 18).at(2, 0, 0, 0).in_tz("UTC")?;
+        let format = DayPartFormat::new();
+        let dt = date(2026, 10, 18).at(3, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("tonight", format.format(&dt, &now)?);
+        let dt = date(2026, 10, 18).at(1, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("tonight", format.format(&dt, &now)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use jiff::civil::date;
+    use jiffy::{DayPart, DayPartFormat, Error};
+
+    #[test]
+    fn day_parts() {
+        let format = DayPartFormat::new();
+        assert_eq!(DayPart::Night, format.day_part(0));
+        assert_eq!(DayPart::Morning, format.day_part(5));
+        assert_eq!(DayPart::Afternoon, format.day_part(16));
+        assert_eq!(DayPart::Evening, format.day_part(17));
+        assert_eq!(DayPart::Night, format.day_part(23));
+    }
+
+    #[test]
+    fn custom_hours() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(3, 0, 0, 0).in_tz("UTC")?;
+        let format = DayPartFormat::new().morning(3).evening(19).night(23);
+        let dt = date(2024, 8, 7).at(18, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("this afternoon", format.format(&dt, &now)?);
+        let dt = date(2024, 8, 7).at(2, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("last night", format.format(&dt, &now)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reference_zone() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(14, 0, 0, 0).in_tz("America/New_York")?;
+        // 07:00 in New York
+        let dt = date(2024, 8, 7).at(11, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("this morning", DayPartFormat::new().format(&dt, &now)?);
+        Ok(())
+    }
+
+    #[test]
+    fn hours_out_of_order() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(14, 0, 0, 0).in_tz("UTC")?;
+        let err = DayPartFormat::new()
+            .afternoon(18)
+            .format(&now, &now)
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+macro_rules! distant_test  {
+    ($($name:ident: $days:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from(jiff::Span::new().days($days));
+            assert_eq!($text, ht.to_text_en(Accuracy::Rough, Tense::Past)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod rough {
+    use jiffy::{Accuracy, HumanTime, Tense};
+
+    // test_name: Days, "Rough text"
+    distant_test! {
+        below_thousand: 999 * 365, "999 years ago",
+        thousand: 1000 * 365, "1,000 years ago",
+        over_thousand: 1000 * 365 + 1, "over 1,000 years ago",
+        over_nine_thousand: 9437 * 365, "over 9,000 years ago",
+        over_twelve_thousand: 12_345 * 365, "over 12,000 years ago",
+        max: 7_304_484, "over 20,000 years ago",
+    }
+}
+
+#[cfg(test)]
+mod other {
+    use jiff::civil::date;
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, CalendarTime, FormatOptions, HumanTime, Tense};
+
+    #[test]
+    fn qualifiers_and_parse() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-9437 * 365).days());
+        let options = FormatOptions::new().qualifiers(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?;
+        assert_eq!("over 9,000 years ago", text);
+        assert_eq!(HumanTime::from((-9000 * 365).days()), text.parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn precise() -> anyhow::Result<()> {
+        let ht = HumanTime::from(7_304_484.days());
+        assert_eq!(
+            "20,012 years, 3 months and 14 days",
+            ht.to_text_en(Accuracy::Precise, Tense::Present)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn precise_thousand() -> anyhow::Result<()> {
+        let ht = HumanTime::from((999 * 365).days());
+        assert_eq!(
+            "999 years",
+            ht.to_text_en(Accuracy::Precise, Tense::Present)?
+        );
+        let ht = HumanTime::from((1000 * 365).days());
+        let text = ht.to_text_en(Accuracy::Precise, Tense::Past)?;
+        assert_eq!("1,000 years ago", text);
+        assert_eq!(HumanTime::from((-1000 * 365).days()), text.parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_thousands() -> anyhow::Result<()> {
+        assert_eq!(HumanTime::from(12_345.days()), "in 12,345 days".parse()?);
+        assert_eq!(
+            HumanTime::from(12.days().hours(3)),
+            "12 days, 3 hours".parse()?
+        );
+        assert!("in 12,34 days".parse::<HumanTime>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn centuries_of_dates() -> anyhow::Result<()> {
+        let now = date(2026, 10, 18).in_tz("UTC")?;
+        let dt = date(1850, 3, 1).in_tz("UTC")?;
+        assert_eq!("in the 1800s", CalendarTime::new(&dt, &now).to_text_en()?);
+        let dt = date(2250, 3, 1).in_tz("UTC")?;
+        assert_eq!("in the 2200s", CalendarTime::new(&dt, &now).to_text_en()?);
+        let dt = date(1950, 3, 1).in_tz("UTC")?;
+        assert_eq!("76 years ago", CalendarTime::new(&dt, &now).to_text_en()?);
+        Ok(())
+    }
+}
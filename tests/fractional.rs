@@ -0,0 +1,63 @@
+macro_rules! fractional_test  {
+    ($($name:ident: $duration:expr, $unit:expr, $decimals:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from($duration);
+            let text = ht.to_text_en_fractional($unit, $decimals, Tense::Present)?;
+            assert_eq!($text, text);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod duration {
+    use jiff::ToSpan;
+    use jiffy::{HumanTime, Tense};
+
+    // test_name: Duration expression, Unit, Decimals, "Fractional text"
+    fractional_test! {
+        zero: jiff::Span::default(), None, 1, "0.0 seconds",
+        plus_1s: 1.seconds(), None, 0, "1 second",
+        plus_1s_decimals: 1.seconds(), None, 1, "1.0 seconds",
+        plus_250ms: 250.milliseconds(), None, 0, "250 ms",
+        plus_90s: 90.seconds(), None, 1, "1.5 minutes",
+        plus_90m: 90.minutes(), None, 1, "1.5 hours",
+        minus_90m: (-90).minutes(), None, 1, "1.5 hours",
+        plus_90m_in_minutes: 90.minutes(), Some(jiff::Unit::Minute), 0, "90 minutes",
+        plus_90m_in_days: 90.minutes(), Some(jiff::Unit::Day), 2, "0.06 days",
+        plus_36h: 36.hours(), None, 1, "1.5 days",
+        plus_10d: 10.days(), None, 1, "1.4 weeks",
+        plus_45d: 45.days(), None, 1, "1.5 months",
+        plus_45d_in_weeks: 45.days(), Some(jiff::Unit::Week), 2, "6.43 weeks",
+        plus_840d: 840.days(), None, 1, "2.3 years",
+        plus_365d: 365.days(), None, 0, "1 year",
+        rounded_to_hour: 59.minutes().seconds(58), None, 1, "1.0 hours",
+        rounded_to_minute: 59.seconds().milliseconds(990), None, 1, "1.0 minutes",
+        not_rounded_up: 59.minutes().seconds(20), None, 1, "59.3 minutes",
+        rounded_in_given_unit: 59.minutes().seconds(58), Some(jiff::Unit::Minute), 1,
+            "60.0 minutes",
+    }
+}
+
+#[cfg(test)]
+mod tense {
+    use jiff::ToSpan;
+    use jiffy::{HumanTime, Tense};
+
+    #[test]
+    fn past() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-90).minutes());
+        let text = ht.to_text_en_fractional(None, 1, Tense::Past)?;
+        assert_eq!("1.5 hours ago", text);
+        Ok(())
+    }
+
+    #[test]
+    fn future() -> anyhow::Result<()> {
+        let ht = HumanTime::from(840.days());
+        let text = ht.to_text_en_fractional(Some(jiff::Unit::Year), 2, Tense::Future)?;
+        assert_eq!("in 2.30 years", text);
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod zoned {
+    use jiff::civil::date;
+    use jiffy::{Accuracy, FormatOptions, HtmlTime};
+
+    fn now() -> jiff::Zoned {
+        date(2026, 10, 18)
+            .at(13, 0, 0, 0)
+            .in_tz("Europe/Paris")
+            .unwrap()
+    }
+
+    #[test]
+    fn past() -> anyhow::Result<()> {
+        let dt = date(2026, 10, 18).at(10, 0, 0, 0).in_tz("Europe/Paris")?;
+        let html = HtmlTime::new(&dt, &now())?.to_html()?;
+        assert_eq!(
+            r#"<time datetime="2026-10-18T08:00:00Z">3 hours ago</time>"#,
+            html
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn future_with_tooltip() -> anyhow::Result<()> {
+        let dt = date(2026, 10, 19).at(13, 30, 0, 0).in_tz("Europe/Paris")?;
+        let html = HtmlTime::new(&dt, &now())?.tooltip(true).to_string();
+        assert_eq!(
+            r#"<time datetime="2026-10-19T11:30:00Z" title="Mon, 19 Oct 2026 13:30 CEST">in a day</time>"#,
+            html
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn precise() -> anyhow::Result<()> {
+        let dt = date(2026, 10, 18).at(11, 30, 0, 0).in_tz("Europe/Paris")?;
+        let html = HtmlTime::new(&dt, &now())?
+            .accuracy(Accuracy::Precise)
+            .to_html()?;
+        assert_eq!(
+            r#"<time datetime="2026-10-18T09:30:00Z">1 hour and 30 minutes ago</time>"#,
+            html
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn options() -> anyhow::Result<()> {
+        let dt = date(2026, 10, 18).at(11, 30, 0, 0).in_tz("Europe/Paris")?;
+        let html = HtmlTime::new(&dt, &now())?
+            .options(FormatOptions::new().idioms(true))
+            .to_html()?;
+        assert_eq!(
+            r#"<time datetime="2026-10-18T09:30:00Z">an hour and a half ago</time>"#,
+            html
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn escaped_tooltip() -> anyhow::Result<()> {
+        let html = HtmlTime::new(&now(), &now())?
+            .tooltip_format(r#""<%H:%M>" & 'co'"#)
+            .to_html()?;
+        assert_eq!(
+            r#"<time datetime="2026-10-18T11:00:00Z" title="&quot;&lt;13:00&gt;&quot; &amp; &#39;co&#39;">now</time>"#,
+            html
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_tooltip() -> anyhow::Result<()> {
+        let html = HtmlTime::new(&now(), &now())?
+            .tooltip_format("%H %")
+            .to_html();
+        assert!(html.is_err());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod timestamp {
+    use jiffy::HtmlTime;
+
+    #[test]
+    fn tooltip_in_utc() -> anyhow::Result<()> {
+        let now: jiff::Timestamp = "2026-10-18T10:00:00Z".parse()?;
+        let ts: jiff::Timestamp = "2026-10-18T09:15:00Z".parse()?;
+        let html = HtmlTime::from_timestamp(ts, now)?.tooltip(true).to_html()?;
+        assert_eq!(
+            r#"<time datetime="2026-10-18T09:15:00Z" title="Sun, 18 Oct 2026 09:15 UTC">45 minutes ago</time>"#,
+            html
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod duration {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, HtmlTime};
+
+    #[test]
+    fn span() -> anyhow::Result<()> {
+        let html = HtmlTime::from_span(2.hours().minutes(30))
+            .accuracy(Accuracy::Precise)
+            .tooltip(true)
+            .to_html()?;
+        assert_eq!(
+            r#"<time datetime="PT2H30M">2 hours and 30 minutes</time>"#,
+            html
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn negative_span() -> anyhow::Result<()> {
+        let html = HtmlTime::from_span((-3).hours()).to_html()?;
+        assert_eq!(r#"<time datetime="PT3H">3 hours</time>"#, html);
+        Ok(())
+    }
+
+    #[test]
+    fn span_in_days() -> anyhow::Result<()> {
+        let html = HtmlTime::from_span(15.days().hours(3).milliseconds(250))
+            .accuracy(Accuracy::Precise)
+            .to_html()?;
+        assert_eq!(
+            r#"<time datetime="P15DT3H0.250S">15 days, 3 hours and 250 ms</time>"#,
+            html
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn empty_span() -> anyhow::Result<()> {
+        let html = HtmlTime::from_span(jiff::Span::new()).to_html()?;
+        assert_eq!(r#"<time datetime="PT0S">now</time>"#, html);
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+macro_rules! hybrid_test  {
+    ($($name:ident: $datetime:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("UTC")?;
+            let dt = $datetime.in_tz("UTC")?;
+            assert_eq!($text, HybridFormat::new().format(&dt, &now)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod default {
+    use jiff::civil::date;
+    use jiffy::HybridFormat;
+
+    // test_name: Date and time, "Text"
+    hybrid_test! {
+        now: date(2024, 8, 7).at(10, 0, 0, 0), "now",
+        hours_ago: date(2024, 8, 7).at(7, 0, 0, 0), "3 hours ago",
+        days_ago: date(2024, 8, 4).at(10, 0, 0, 0), "3 days ago",
+        at_cutoff: date(2024, 7, 7).at(10, 0, 0, 0), "a month ago",
+        past_cutoff: date(2024, 7, 7).at(9, 59, 0, 0), "on Jul 7",
+        other_year: date(2023, 3, 3).at(10, 0, 0, 0), "on Mar 3, 2023",
+        future: date(2024, 8, 10).at(10, 0, 0, 0), "in 3 days",
+        future_past_cutoff: date(2024, 12, 25).at(10, 0, 0, 0), "on Dec 25",
+        future_other_year: date(2025, 1, 2).at(10, 0, 0, 0), "on Jan 2, 2025",
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use jiff::civil::date;
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, HybridFormat};
+
+    #[test]
+    fn cutoff() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("UTC")?;
+        let format = HybridFormat::new().cutoff(1.days());
+        let dt = date(2024, 8, 6).at(12, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("22 hours ago", format.format(&dt, &now)?);
+        let dt = date(2024, 8, 5).at(12, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("on Aug 5", format.format(&dt, &now)?);
+        Ok(())
+    }
+
+    #[test]
+    fn templates_and_accuracy() -> anyhow::Result<()> {
+        let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("UTC")?;
+        let format = HybridFormat::new()
+            .accuracy(Accuracy::Precise)
+            .same_year("%d/%m")
+            .other_year("%d/%m/%Y");
+        let dt = date(2024, 8, 6).at(8, 30, 0, 0).in_tz("UTC")?;
+        assert_eq!(
+            "1 day, 1 hour and 30 minutes ago",
+            format.format(&dt, &now)?
+        );
+        let dt = date(2024, 2, 1).at(8, 30, 0, 0).in_tz("UTC")?;
+        assert_eq!("01/02", format.format(&dt, &now)?);
+        let dt = date(2022, 2, 1).at(8, 30, 0, 0).in_tz("UTC")?;
+        assert_eq!("01/02/2022", format.format(&dt, &now)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reference_zone() -> anyhow::Result<()> {
+        // 2023-12-31 in UTC, but already 2024 in Tokyo
+        let now = date(2024, 8, 7).at(10, 0, 0, 0).in_tz("Asia/Tokyo")?;
+        let dt = date(2023, 12, 31).at(20, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("on Jan 1", HybridFormat::new().format(&dt, &now)?);
+        Ok(())
+    }
+
+    #[test]
+    fn timestamps() -> anyhow::Result<()> {
+        let now: jiff::Timestamp = "2024-08-07T10:00:00Z".parse()?;
+        let format = HybridFormat::new();
+        let ts: jiff::Timestamp = "2024-08-07T09:00:00Z".parse()?;
+        assert_eq!("an hour ago", format.format_timestamp(ts, now)?);
+        let ts: jiff::Timestamp = "2021-08-07T09:00:00Z".parse()?;
+        assert_eq!("on Aug 7, 2021", format.format_timestamp(ts, now)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+macro_rules! idiom_test  {
+    ($($name:ident: $duration:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from($duration);
+            let options = FormatOptions::new().idioms(true);
+            let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+            assert_eq!($text, text);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod duration {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    // test_name: Duration expression, "Rough text with idioms"
+    idiom_test! {
+        now: jiff::Span::default(), "now",
+        plus_12m: 12.minutes(), "12 minutes",
+        plus_15m: 15.minutes(), "a quarter of an hour",
+        plus_29m: 29.minutes(), "half an hour",
+        minus_30m: (-30).minutes(), "half an hour",
+        plus_45m: 45.minutes(), "three quarters of an hour",
+        plus_1h: 1.hours(), "an hour",
+        plus_90m: 90.minutes(), "an hour and a half",
+        plus_150m: 150.minutes(), "2 and a half hours",
+        plus_12h: 12.hours(), "half a day",
+        plus_26h: 26.hours(), "a day",
+        plus_36h: 36.hours(), "a day and a half",
+        plus_84h: 84.hours(), "3 and a half days",
+        plus_10d_12h: 10.days().checked_add(12.hours())?, "a week and a half",
+        plus_14d: 14.days(), "2 weeks",
+        plus_45d: 45.days(), "a month and a half",
+        plus_183d: 183.days(), "half a year",
+        plus_365d: 365.days(), "a year",
+        plus_548d: 548.days(), "a year and a half",
+        plus_913d: 913.days(), "2 and a half years",
+    }
+}
+
+#[cfg(test)]
+mod tense {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    #[test]
+    fn past() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-30).minutes());
+        let options = FormatOptions::new().idioms(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?;
+        assert_eq!("half an hour ago", text);
+        Ok(())
+    }
+
+    #[test]
+    fn disabled() -> anyhow::Result<()> {
+        let ht = HumanTime::from(90.minutes());
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Future, FormatOptions::new())?;
+        assert_eq!("in an hour", text);
+        Ok(())
+    }
+
+    #[test]
+    fn precise() -> anyhow::Result<()> {
+        let ht = HumanTime::from(90.minutes());
+        let options = FormatOptions::new().idioms(true);
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Present, options)?;
+        assert_eq!("90 minutes", text);
+        Ok(())
+    }
+}
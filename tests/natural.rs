@@ -0,0 +1,155 @@
+macro_rules! natural_test  {
+    ($($name:ident: $text:expr, $expected:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            // Wednesday
+            let reference = date(2024, 8, 7).at(10, 30, 0, 0).in_tz("Asia/Jakarta")?;
+            let zdt = jiffy::parse_zoned($text, &reference)?;
+            assert_eq!($expected.in_tz("Asia/Jakarta")?, zdt);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod text {
+    use jiff::civil::date;
+
+    // test_name: "Text", Expected civil datetime
+    natural_test! {
+        now: "now", date(2024, 8, 7).at(10, 30, 0, 0),
+        today: "today", date(2024, 8, 7).at(10, 30, 0, 0),
+        tomorrow_at_5pm: "tomorrow at 5pm", date(2024, 8, 8).at(17, 0, 0, 0),
+        tomorrow_at_5_30_pm: "Tomorrow at 5:30 PM", date(2024, 8, 8).at(17, 30, 0, 0),
+        yesterday_at_noon: "yesterday at noon", date(2024, 8, 6).at(12, 0, 0, 0),
+        at_midnight: "at midnight", date(2024, 8, 7).at(0, 0, 0, 0),
+        at_17: "at 17:45", date(2024, 8, 7).at(17, 45, 0, 0),
+        at_12am: "at 12am", date(2024, 8, 7).at(0, 0, 0, 0),
+        time_first: "9am tomorrow", date(2024, 8, 8).at(9, 0, 0, 0),
+        friday: "friday", date(2024, 8, 9).at(10, 30, 0, 0),
+        wednesday: "wednesday", date(2024, 8, 7).at(10, 30, 0, 0),
+        next_wednesday: "next wednesday", date(2024, 8, 14).at(10, 30, 0, 0),
+        next_friday: "next Friday", date(2024, 8, 9).at(10, 30, 0, 0),
+        last_tuesday: "last tue at 8am", date(2024, 8, 6).at(8, 0, 0, 0),
+        next_week: "next week", date(2024, 8, 14).at(10, 30, 0, 0),
+        last_month: "last month", date(2024, 7, 7).at(10, 30, 0, 0),
+        next_year: "next year", date(2025, 8, 7).at(10, 30, 0, 0),
+        last_day_of_the_month: "last day of the month", date(2024, 8, 31).at(10, 30, 0, 0),
+        last_day_of_next_month: "last day of next month at noon", date(2024, 9, 30).at(12, 0, 0, 0),
+        first_day_of_last_month: "first day of last month", date(2024, 7, 1).at(10, 30, 0, 0),
+        last_day_of_the_year: "last day of the year", date(2024, 12, 31).at(10, 30, 0, 0),
+        in_2_weeks_at_noon: "in 2 weeks at noon", date(2024, 8, 21).at(12, 0, 0, 0),
+        in_1_month: "in a month", date(2024, 9, 7).at(10, 30, 0, 0),
+        in_2_hours: "in 2 hours", date(2024, 8, 7).at(12, 30, 0, 0),
+        days_ago: "3 days ago at 9:15", date(2024, 8, 4).at(9, 15, 0, 0),
+    }
+}
+
+#[cfg(test)]
+mod time_zone {
+    use jiff::civil::date;
+
+    #[test]
+    fn across_dst() -> anyhow::Result<()> {
+        let reference = date(2024, 3, 9).at(10, 0, 0, 0).in_tz("America/New_York")?;
+        let zdt = jiffy::parse_zoned("tomorrow at 9am", &reference)?;
+        assert_eq!(
+            date(2024, 3, 10).at(9, 0, 0, 0).in_tz("America/New_York")?,
+            zdt
+        );
+        assert_eq!(
+            "2024-03-10T09:00:00-04:00[America/New_York]",
+            zdt.to_string()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use jiff::civil::date;
+    use jiffy::Error;
+
+    fn reference() -> jiff::Zoned {
+        date(2024, 8, 7)
+            .at(10, 30, 0, 0)
+            .in_tz("Asia/Jakarta")
+            .unwrap()
+    }
+
+    #[test]
+    fn ambiguous_hour() {
+        let err = jiffy::parse_zoned("tomorrow at 5", &reference()).unwrap_err();
+        assert_eq!(
+            Error::Ambiguous {
+                token: "5".to_string(),
+                position: 12,
+                hint: "add am or pm, or use a 24-hour time such as 17:00",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn invalid_hour() {
+        let err = jiffy::parse_zoned("at 13pm", &reference()).unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "13pm".to_string(),
+                position: 3,
+                expected: "an hour between 1 and 12",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn invalid_time() {
+        let err = jiffy::parse_zoned("at 25:00", &reference()).unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "25:00".to_string(),
+                position: 3,
+                expected: "a valid time of day",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn two_days() {
+        let err = jiffy::parse_zoned("tomorrow friday", &reference()).unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "friday".to_string(),
+                position: 9,
+                expected: "a time of day",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn unknown_word() {
+        let err = jiffy::parse_zoned("next fortnight", &reference()).unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "fortnight".to_string(),
+                position: 5,
+                expected: "a weekday, `week`, `month` or `year`",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let err = jiffy::parse_zoned(" ", &reference()).unwrap_err();
+        assert_eq!(
+            Error::UnexpectedEnd {
+                expected: "a date or time"
+            },
+            err
+        );
+    }
+}
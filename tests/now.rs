@@ -0,0 +1,103 @@
+macro_rules! now_test  {
+    ($($name:ident: $duration:expr, $tense:expr, $options:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from($duration);
+            assert_eq!($text, ht.to_text_en_with(Accuracy::Rough, $tense, $options)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod rough {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, NowWording, Tense};
+
+    fn wording(wording: NowWording) -> FormatOptions {
+        FormatOptions::new().now_wording(wording)
+    }
+
+    // test_name: Duration, Tense, Options, "Rough text"
+    now_test! {
+        default: (-5).seconds(), Tense::Present, FormatOptions::new(), "now",
+        default_edge: (-11).seconds(), Tense::Past, FormatOptions::new(), "11 seconds ago",
+        zero_window: (-5).seconds(), Tense::Past, FormatOptions::new().now_window(0),
+            "5 seconds ago",
+        zero_window_empty: 0.seconds(), Tense::Present, FormatOptions::new().now_window(0),
+            "0 seconds",
+        just_now: (-5).seconds(), Tense::Present, wording(NowWording::JustNow), "just now",
+        just_now_future: 5.seconds(), Tense::Future, wording(NowWording::JustNow),
+            "in a moment",
+        moment_ago: (-5).seconds(), Tense::Past, wording(NowWording::Moment), "a moment ago",
+        moment_present: (-5).seconds(), Tense::Present, wording(NowWording::Moment), "now",
+        moment_future: 5.seconds(), Tense::Future, wording(NowWording::Moment),
+            "moments from now",
+        moment_empty: 0.seconds(), Tense::Present, wording(NowWording::Moment), "now",
+        now_wording: (-5).seconds(), Tense::Present, wording(NowWording::Now), "now",
+    }
+}
+
+#[cfg(test)]
+mod window {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, HybridFormat, NowWording, Tense};
+
+    #[test]
+    fn wider_window() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-40).seconds());
+        let options = FormatOptions::new()
+            .now_window(60)
+            .now_wording(NowWording::JustNow);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+        assert_eq!("just now", text);
+        // The wording tells the tense on its own
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?;
+        assert_eq!("just now", text);
+        Ok(())
+    }
+
+    #[test]
+    fn precise_empty_span() -> anyhow::Result<()> {
+        let ht = HumanTime::now();
+        assert_eq!(
+            "0 seconds",
+            ht.to_text_en(Accuracy::Precise, Tense::Present)?
+        );
+        let options = FormatOptions::new().now_wording(NowWording::JustNow);
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Present, options)?;
+        assert_eq!("just now", text);
+        // Non-empty spans stay exact
+        let ht = HumanTime::from((-5).seconds());
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Past, options)?;
+        assert_eq!("5 seconds ago", text);
+        Ok(())
+    }
+
+    #[test]
+    fn formatters_follow_options() -> anyhow::Result<()> {
+        let now = jiff::civil::date(2026, 10, 18)
+            .at(12, 0, 0, 0)
+            .in_tz("UTC")?;
+        let dt = now.checked_sub(20.seconds())?;
+        let options = FormatOptions::new()
+            .now_window(30)
+            .now_wording(NowWording::Moment);
+        let format = HybridFormat::new().options(options);
+        assert_eq!("a moment ago", format.format(&dt, &now)?);
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> anyhow::Result<()> {
+        for text in &[
+            "just now",
+            "a moment ago",
+            "in a moment",
+            "moments from now",
+        ] {
+            assert_eq!(HumanTime::now(), text.parse()?);
+        }
+        Ok(())
+    }
+}
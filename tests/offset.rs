@@ -0,0 +1,63 @@
+macro_rules! offset_test  {
+    ($($name:ident: $tz:expr, $reference:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            // Standard time in the northern hemisphere
+            let ts: jiff::Timestamp = "2026-01-15T12:00:00Z".parse()?;
+            let tz = TimeZone::get($tz)?;
+            let reference = TimeZone::get($reference)?;
+            assert_eq!($text, OffsetDifference::new(&tz, &reference, ts).to_text_en()?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod zones {
+    use jiff::tz::TimeZone;
+    use jiffy::OffsetDifference;
+
+    // test_name: "Time zone", "Reference time zone", "Text"
+    offset_test! {
+        same: "Europe/Paris", "Europe/Berlin", "same time as you",
+        ahead: "Asia/Tokyo", "UTC", "9 hours ahead of you",
+        behind: "America/New_York", "UTC", "5 hours behind you",
+        one_hour: "Europe/Paris", "Europe/London", "1 hour ahead of you",
+        half_hour: "Asia/Kolkata", "UTC", "5 hours and 30 minutes ahead of you",
+        three_quarters: "Asia/Kathmandu", "UTC", "5 hours and 45 minutes ahead of you",
+        half_hour_ahead: "America/St_Johns", "America/Halifax", "30 minutes ahead of you",
+        half_hour_behind: "America/Halifax", "America/St_Johns", "30 minutes behind you",
+        across_date_line: "Pacific/Honolulu", "Pacific/Auckland", "23 hours behind you",
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use jiff::tz::TimeZone;
+    use jiff::ToSpan;
+    use jiffy::OffsetDifference;
+
+    #[test]
+    fn daylight_saving_time() -> anyhow::Result<()> {
+        let new_york = TimeZone::get("America/New_York")?;
+        let london = TimeZone::get("Europe/London")?;
+        // New York already moved its clocks, London not yet
+        let ts: jiff::Timestamp = "2026-03-20T12:00:00Z".parse()?;
+        let diff = OffsetDifference::new(&new_york, &london, ts);
+        assert_eq!("4 hours behind you", diff.to_text_en()?);
+        let ts: jiff::Timestamp = "2026-04-20T12:00:00Z".parse()?;
+        let diff = OffsetDifference::new(&new_york, &london, ts);
+        assert_eq!("5 hours behind you", diff.to_text_en()?);
+        Ok(())
+    }
+
+    #[test]
+    fn reference_name_and_span() -> anyhow::Result<()> {
+        let ts: jiff::Timestamp = "2026-01-15T12:00:00Z".parse()?;
+        let kolkata = TimeZone::get("Asia/Kolkata")?;
+        let diff = OffsetDifference::new(&TimeZone::UTC, &kolkata, ts).reference_name("Kolkata");
+        assert_eq!("5 hours and 30 minutes behind Kolkata", diff.to_string());
+        assert_eq!((-5).hours().minutes(-30), diff.span());
+        Ok(())
+    }
+}
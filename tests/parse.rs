@@ -0,0 +1,163 @@
+macro_rules! parse_test  {
+    ($($name:ident: $text:expr, $duration:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht: HumanTime = $text.parse()?;
+            assert_eq!(HumanTime::from($duration), ht);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod text {
+    use jiff::ToSpan;
+    use jiffy::HumanTime;
+
+    // test_name: "Text", Expected duration
+    parse_test! {
+        now: "now", jiff::Span::default(),
+        zero: "0 seconds", jiff::Span::default(),
+        plus_15s: "in 15 seconds", 15.seconds(),
+        minus_15s: "15 seconds ago", (-15).seconds(),
+        present_15s: "15 seconds", 15.seconds(),
+        plus_1h: "in an hour", 1.hours(),
+        minus_1d: "a day ago", (-1).days(),
+        plus_2w: "in 2 weeks", 14.days(),
+        minus_1w: "1 week ago", (-7).days(),
+        plus_1mo: "in a month", 30.days(),
+        plus_1y: "in a year", 365.days(),
+        plus_1mo_15d: "1 month and 15 days", 45.days(),
+        plus_1y_2mo_3d: "in 1 year, 2 months and 3 days", 428.days(),
+        plus_subsecond: "1 hour, 2 minutes, 3 seconds, 250 ms, 3 µs and 12 ns",
+            1.hours().minutes(2).seconds(3).milliseconds(250).microseconds(3).nanoseconds(12),
+        plus_us: "7 us", 7.microseconds(),
+        plus_30m: "half an hour", 30.minutes(),
+        plus_15m: "in a quarter of an hour", 15.minutes(),
+        minus_45m: "three quarters of an hour ago", (-45).minutes(),
+        plus_90m: "an hour and a half", 1.hours().minutes(30),
+        plus_150m: "2 and a half hours", 2.hours().minutes(30),
+        plus_18mo: "a year and a half", 547.days().hours(12),
+        minus_2h_qualified: "about 2 hours ago", (-2).hours(),
+        plus_mixed_case: "In 2 Hours", 2.hours(),
+        plus_extra_spaces: "  in   2 hours ", 2.hours(),
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use jiffy::{Error, HumanTime};
+
+    #[test]
+    fn unknown_unit() {
+        let err = "2 fortnights".parse::<HumanTime>().unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "fortnights".to_string(),
+                position: 2,
+                expected: "a unit",
+            },
+            err
+        );
+        assert_eq!(
+            "expected a unit, found `fortnights` at position 2",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn both_tenses() {
+        let err = jiffy::parse("in 2 hours ago").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "ago".to_string(),
+                position: 11,
+                expected: "end of input",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn eternity() {
+        let err = jiffy::parse("eternity").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "eternity".to_string(),
+                position: 0,
+                expected: "a count",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn signed_count() {
+        let err = jiffy::parse("in -2 hours").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "-2".to_string(),
+                position: 3,
+                expected: "a count",
+            },
+            err
+        );
+        assert!(jiffy::parse("-2 hours").is_err());
+        assert!(jiffy::parse("+2 hours ago").is_err());
+    }
+
+    #[test]
+    fn missing_unit() {
+        let err = jiffy::parse("2").unwrap_err();
+        assert_eq!(Error::UnexpectedEnd { expected: "a unit" }, err);
+    }
+
+    #[test]
+    fn empty() {
+        let err = jiffy::parse("").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedEnd {
+                expected: "a count"
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn dangling_separator() {
+        let err = jiffy::parse("1 hour and").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedEnd {
+                expected: "a count"
+            },
+            err
+        );
+    }
+}
+
+#[cfg(test)]
+mod round_trip {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, HumanTime, Tense};
+
+    #[test]
+    fn precise() -> anyhow::Result<()> {
+        let ht = HumanTime::from(400.days().hours(5).minutes(3));
+        let text = ht.to_text_en(Accuracy::Precise, Tense::Past)?;
+        assert_eq!("1 year, 1 month, 5 days, 5 hours and 3 minutes ago", text);
+        let parsed = jiffy::parse(&text)?;
+        assert_eq!(
+            ht.to_text_en(Accuracy::Precise, Tense::Present)?,
+            parsed.to_text_en(Accuracy::Precise, Tense::Present)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rough() -> anyhow::Result<()> {
+        let ht = HumanTime::from(3.days());
+        let text = ht.to_text_en(Accuracy::Rough, Tense::Future)?;
+        assert_eq!(ht, jiffy::parse(&text)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+macro_rules! pattern_test  {
+    ($($name:ident: $pattern:expr, $duration:expr, $formatted:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let pattern = jiffy::Pattern::new($pattern)?;
+            assert_eq!($formatted, pattern.format(HumanTime::from($duration))?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod format {
+    use jiff::ToSpan;
+    use jiffy::HumanTime;
+
+    // test_name: "Pattern", Duration, "Formatted text"
+    pattern_test! {
+        hours_minutes: "{hours}h {minutes:02}m", 1.hours().minutes(5), "1h 05m",
+        minutes_balanced: "{hours}h {minutes:02}m", 90.minutes(), "1h 30m",
+        days_carried: "{hours}h {minutes:02}m", 1.days().hours(2).minutes(5), "26h 05m",
+        smaller_dropped: "{hours}h", 2.hours().minutes(59), "2h",
+        all_dropped: "{days}d", 23.hours(), "0d",
+        years_months_days: "{years}y {months}mo {days}d", 428.days(), "1y 2mo 3d",
+        years_carried: "{months}mo {days}d", 428.days(), "14mo 8d",
+        weeks: "{weeks}w {days}d", 17.days(), "2w 3d",
+        clock: "{hours:02}:{minutes:02}:{seconds:02}", 4.hours().seconds(7), "04:00:07",
+        space_pad: "[{minutes:3}]", 5.minutes(), "[  5]",
+        subsecond: "{seconds}.{millis:03}s", 2.seconds().milliseconds(50), "2.050s",
+        sign: "{sign}{minutes}m", (-90).minutes(), "-90m",
+        sign_positive: "{sign}{minutes}m", 90.minutes(), "90m",
+        unsigned: "{minutes} minutes", (-90).minutes(), "90 minutes",
+        escaped: "{{{days}}}", 3.days(), "{3}",
+        literal_only: "soon", 3.days(), "soon",
+        percent: "%yy %mo %dd", 428.days(), "1 2 3",
+        percent_units: "%yyy %momo %ddd", 428.days(), "1y 2mo 3d",
+        percent_clock: "%02hh:%02mi:%02ss", 4.hours().seconds(7), "04:00:07",
+        percent_subsecond: "%ss.%03mss", 2.seconds().milliseconds(50), "2.050s",
+        percent_small: "%ms %us %ns", 1.milliseconds().microseconds(2).nanoseconds(3),
+            "1 2 3",
+        percent_weeks: "%www %hhh", 8.days().hours(1), "1w 25h",
+        percent_sign: "%+%mi", (-90).minutes(), "-90",
+        percent_literal: "%hh%%", 3.hours(), "3%",
+        mixed: "{days}d %hhh", 1.days().hours(2), "1d 2h",
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use jiffy::{Error, Pattern};
+
+    #[test]
+    fn unknown_directive() {
+        let err = Pattern::new("{hours}h {mins}m").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "mins".to_string(),
+                position: 10,
+                expected: "a unit name or `sign`",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn invalid_width() {
+        let err = Pattern::new("{minutes:x}").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "x".to_string(),
+                position: 9,
+                expected: "a width",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn unclosed_brace() {
+        let err = "{hours".parse::<Pattern>().unwrap_err();
+        assert_eq!(Error::UnexpectedEnd { expected: "`}`" }, err);
+    }
+
+    #[test]
+    fn stray_closing_brace() {
+        let err = Pattern::new("5}").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "}".to_string(),
+                position: 1,
+                expected: "`}}` for a literal brace",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn unknown_percent_directive() {
+        let err = Pattern::new("%hh %qq").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "qq".to_string(),
+                position: 5,
+                expected: "a unit directive, `%+` or `%%`",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn trailing_percent() {
+        let err = Pattern::new("%hh%").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedEnd {
+                expected: "a unit directive, `%+` or `%%`"
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn invalid_percent_width() {
+        let err = Pattern::new("%300hh").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "300".to_string(),
+                position: 1,
+                expected: "a width",
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn padding_flag() {
+        let err = Pattern::new("%-dd days").unwrap_err();
+        assert_eq!(
+            Error::UnexpectedToken {
+                token: "-".to_string(),
+                position: 1,
+                expected: "a width or unit directive, without the `-` flag",
+            },
+            err
+        );
+    }
+}
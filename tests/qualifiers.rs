@@ -0,0 +1,87 @@
+macro_rules! qualifier_test  {
+    ($($name:ident: $duration:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from($duration);
+            let options = FormatOptions::new().qualifiers(true);
+            let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+            assert_eq!($text, text);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod duration {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    // test_name: Duration expression, "Rough text with qualifier"
+    qualifier_test! {
+        now: jiff::Span::default(), "now",
+        plus_15s: 15.seconds(), "15 seconds",
+        plus_46m: 46.minutes(), "about an hour",
+        plus_55m: 55.minutes(), "about an hour",
+        plus_1h: 1.hours(), "an hour",
+        plus_80m: 80.minutes(), "over an hour",
+        plus_100m: 100.minutes(), "almost 2 hours",
+        plus_2h_10m: 2.hours().minutes(10), "about 2 hours",
+        plus_2h_50m: 2.hours().minutes(50), "over 2 hours",
+        plus_23h: 23.hours(), "about a day",
+        plus_40d: 40.days(), "over a month",
+        plus_365d: 365.days(), "a year",
+        plus_400d: 400.days(), "about a year",
+        plus_500d: 500.days(), "over a year",
+        plus_700d: 700.days(), "about 2 years",
+        plus_730d: 730.days(), "2 years",
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Qualifier, Tense};
+
+    #[test]
+    fn past() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-100).minutes());
+        let options = FormatOptions::new().qualifiers(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?;
+        assert_eq!("almost 2 hours ago", text);
+        Ok(())
+    }
+
+    #[test]
+    fn future() -> anyhow::Result<()> {
+        let ht = HumanTime::from(40.days());
+        let options = FormatOptions::new().qualifiers(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Future, options)?;
+        assert_eq!("in over a month", text);
+        Ok(())
+    }
+
+    #[test]
+    fn with_idioms() -> anyhow::Result<()> {
+        let ht = HumanTime::from(92.minutes());
+        let options = FormatOptions::new().idioms(true).qualifiers(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+        assert_eq!("about an hour and a half", text);
+        Ok(())
+    }
+
+    #[test]
+    fn precise() -> anyhow::Result<()> {
+        let ht = HumanTime::from(100.minutes());
+        let options = FormatOptions::new().qualifiers(true);
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Present, options)?;
+        assert_eq!("100 minutes", text);
+        Ok(())
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!("about", Qualifier::About.to_text_en());
+        assert_eq!("almost", Qualifier::Almost.to_text_en());
+        assert_eq!("over", Qualifier::Over.to_text_en());
+    }
+}
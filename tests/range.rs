@@ -0,0 +1,133 @@
+macro_rules! range_test  {
+    ($($name:ident: $start:expr, $end:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let start = $start.in_tz("UTC")?;
+            let end = $end.in_tz("UTC")?;
+            assert_eq!($text, RangeFormat::new().format(&start, &end)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod default {
+    use jiff::civil::date;
+    use jiffy::RangeFormat;
+
+    // test_name: Start, End, "Text"
+    range_test! {
+        same_month: date(2026, 3, 3).at(0, 0, 0, 0), date(2026, 3, 5).at(0, 0, 0, 0),
+            "Mar 3 – 5, 2026",
+        other_months: date(2026, 3, 30).at(0, 0, 0, 0), date(2026, 4, 2).at(0, 0, 0, 0),
+            "Mar 30 – Apr 2, 2026",
+        other_years: date(2025, 12, 30).at(0, 0, 0, 0), date(2026, 1, 2).at(0, 0, 0, 0),
+            "Dec 30, 2025 – Jan 2, 2026",
+        single_day: date(2026, 3, 3).at(0, 0, 0, 0), date(2026, 3, 3).at(0, 0, 0, 0),
+            "Mar 3, 2026",
+        same_meridiem: date(2026, 3, 3).at(15, 0, 0, 0), date(2026, 3, 3).at(17, 0, 0, 0),
+            "3 – 5 PM",
+        other_meridiems: date(2026, 3, 3).at(11, 0, 0, 0), date(2026, 3, 3).at(13, 0, 0, 0),
+            "11 AM – 1 PM",
+        minutes: date(2026, 3, 3).at(9, 30, 0, 0), date(2026, 3, 3).at(10, 45, 0, 0),
+            "9:30 – 10:45 AM",
+        over_days: date(2026, 3, 3).at(9, 0, 0, 0), date(2026, 3, 5).at(17, 30, 0, 0),
+            "Mar 3, 2026, 9 AM – Mar 5, 2026, 5:30 PM",
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use jiff::civil::date;
+    use jiffy::{Error, RangeFormat};
+
+    #[test]
+    fn without_year() -> anyhow::Result<()> {
+        let format = RangeFormat::new().year(false);
+        let start = date(2026, 3, 3).in_tz("UTC")?;
+        let end = date(2026, 3, 5).in_tz("UTC")?;
+        assert_eq!("Mar 3 – 5", format.format(&start, &end)?);
+        let end = date(2027, 1, 5).in_tz("UTC")?;
+        assert_eq!("Mar 3, 2026 – Jan 5, 2027", format.format(&start, &end)?);
+        Ok(())
+    }
+
+    #[test]
+    fn weekday_and_separator() -> anyhow::Result<()> {
+        let format = RangeFormat::new().weekday(true).separator(" to ");
+        let start = date(2026, 3, 3).at(9, 0, 0, 0).in_tz("UTC")?;
+        let end = date(2026, 3, 3).at(17, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("Tuesday 9 AM to 5 PM", format.format(&start, &end)?);
+        Ok(())
+    }
+
+    #[test]
+    fn hour24() -> anyhow::Result<()> {
+        let format = RangeFormat::new().hour24(true);
+        let start = date(2026, 3, 3).at(9, 0, 0, 0).in_tz("UTC")?;
+        let end = date(2026, 3, 3).at(17, 30, 0, 0).in_tz("UTC")?;
+        assert_eq!("09:00 – 17:30", format.format(&start, &end)?);
+        Ok(())
+    }
+
+    #[test]
+    fn length() -> anyhow::Result<()> {
+        let format = RangeFormat::new().length(true);
+        let start = date(2026, 3, 3).at(15, 0, 0, 0).in_tz("UTC")?;
+        let end = date(2026, 3, 3).at(17, 15, 0, 0).in_tz("UTC")?;
+        assert_eq!(
+            "3 – 5:15 PM (2 hours and 15 minutes)",
+            format.format(&start, &end)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn length_in_days() -> anyhow::Result<()> {
+        let format = RangeFormat::new().length(true);
+        let start = date(2026, 3, 3).at(9, 0, 0, 0).in_tz("UTC")?;
+        let end = date(2026, 3, 5).at(17, 30, 0, 0).in_tz("UTC")?;
+        assert_eq!(
+            "Mar 3, 2026, 9 AM – Mar 5, 2026, 5:30 PM (2 days, 8 hours and 30 minutes)",
+            format.format(&start, &end)?
+        );
+
+        let end = date(2026, 3, 5).at(0, 0, 0, 0).in_tz("UTC")?;
+        let start = date(2026, 3, 3).at(0, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("Mar 3 – 5, 2026 (2 days)", format.format(&start, &end)?);
+        Ok(())
+    }
+
+    #[test]
+    fn length_across_dst() -> anyhow::Result<()> {
+        // Clocks in New York move forward on Mar 8, 2026, so the range is 71 hours long
+        let format = RangeFormat::new().length(true);
+        let start = date(2026, 3, 6).at(0, 0, 0, 0).in_tz("America/New_York")?;
+        let end = date(2026, 3, 9).at(0, 0, 0, 0).in_tz("America/New_York")?;
+        assert_eq!("Mar 6 – 9, 2026 (3 days)", format.format(&start, &end)?);
+
+        let end = date(2026, 3, 9).at(12, 0, 0, 0).in_tz("America/New_York")?;
+        assert_eq!(
+            "Mar 6, 2026, 12 AM – Mar 9, 2026, 12 PM (3 days and 12 hours)",
+            format.format(&start, &end)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn end_in_time_zone_of_start() -> anyhow::Result<()> {
+        let start = date(2026, 3, 3).at(9, 0, 0, 0).in_tz("America/New_York")?;
+        let end = date(2026, 3, 3).at(22, 0, 0, 0).in_tz("UTC")?;
+        assert_eq!("9 AM – 5 PM", RangeFormat::new().format(&start, &end)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reversed() -> anyhow::Result<()> {
+        let start = date(2026, 3, 5).in_tz("UTC")?;
+        let end = date(2026, 3, 3).in_tz("UTC")?;
+        let err = RangeFormat::new().format(&start, &end).unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use std::cmp::Ordering;
+
+use jiff::ToSpan;
+use jiffy::{Accuracy, FormatOptions, HumanTime, NowWording, Tense};
+use proptest::prelude::*;
+
+// Seconds at which the rough representation moves to another period, including the
+// thresholds of sub-minute buckets, quarters, decades and centuries
+const ROUGH_BOUNDARIES: [i64; 21] = [
+    10,
+    20,
+    40,
+    45,
+    90,
+    45 * 60,
+    90 * 60,
+    22 * 3600,
+    36 * 3600,
+    6 * 86400 + 12 * 3600,
+    10 * 86400 + 12 * 3600,
+    29 * 86400,
+    45 * 86400,
+    80 * 86400,
+    136 * 86400,
+    345 * 86400,
+    547 * 86400,
+    (9 * 365 + 180) * 86400,
+    15 * 365 * 86400,
+    95 * 365 * 86400,
+    150 * 365 * 86400,
+];
+
+/// Spans made of the units `HumanTime` gets from `jiff::Zoned` and `jiff::Timestamp`, with
+/// up to about 270 years
+fn span() -> impl Strategy<Value = jiff::Span> {
+    (
+        any::<bool>(),
+        0..100_000i32,
+        0..48i32,
+        0..120i64,
+        0..120i64,
+        0..1_000i64,
+        0..1_000i64,
+        0..1_000i64,
+    )
+        .prop_map(|(negative, d, h, m, s, ms, us, ns)| {
+            let span = d
+                .days()
+                .hours(h)
+                .minutes(m)
+                .seconds(s)
+                .milliseconds(ms)
+                .microseconds(us)
+                .nanoseconds(ns);
+            if negative {
+                span.negate()
+            } else {
+                span
+            }
+        })
+}
+
+/// Spans within a few seconds of where the rough representation changes
+fn rough_boundary() -> impl Strategy<Value = jiff::Span> {
+    (
+        any::<bool>(),
+        proptest::sample::select(&ROUGH_BOUNDARIES[..]),
+        -3..=3i64,
+    )
+        .prop_map(|(negative, boundary, delta)| {
+            let span = (boundary + delta).seconds();
+            if negative {
+                span.negate()
+            } else {
+                span
+            }
+        })
+}
+
+/// Options of the rough representation, in any combination, along with their now window
+fn options() -> impl Strategy<Value = (FormatOptions, u32)> {
+    (
+        (
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+            any::<bool>(),
+        ),
+        now_window(),
+        proptest::option::of(now_wording()),
+        proptest::option::of(1..=6u8),
+    )
+        .prop_map(
+            |(
+                (idioms, qualifiers, quarters, decades, centuries, buckets),
+                window,
+                wording,
+                digits,
+            )| {
+                let options = FormatOptions::new()
+                    .idioms(idioms)
+                    .qualifiers(qualifiers)
+                    .quarters(quarters)
+                    .decades(decades)
+                    .centuries(centuries)
+                    .sub_minute_buckets(buckets)
+                    .now_window(window);
+                let options = match digits {
+                    Some(digits) => options.significant_digits(digits),
+                    None => options,
+                };
+                match wording {
+                    Some(wording) => (options.now_wording(wording), window),
+                    None => (options, window),
+                }
+            },
+        )
+}
+
+/// Now windows around the default of 11 seconds, from none to a minute
+fn now_window() -> impl Strategy<Value = u32> {
+    prop_oneof![Just(11), Just(0), 0..=60u32]
+}
+
+fn now_wording() -> impl Strategy<Value = NowWording> {
+    prop_oneof![
+        Just(NowWording::Now),
+        Just(NowWording::JustNow),
+        Just(NowWording::Moment)
+    ]
+}
+
+fn seconds(span: jiff::Span) -> f64 {
+    span.total(jiff::Unit::Second).unwrap()
+}
+
+fn parse(text: &str) -> Result<jiff::Span, TestCaseError> {
+    jiffy::parse(text)
+        .map(jiff::Span::from)
+        .map_err(|err| TestCaseError::fail(format!("`{}`: {}", text, err)))
+}
+
+/// The documented tolerance of the rough representation: the parsed amount is off by at most
+/// half of itself, or by less than 11 seconds, or less than the now `window` for "now"
+fn assert_rough(
+    original: jiff::Span,
+    parsed: jiff::Span,
+    text: &str,
+    window: u32,
+) -> Result<(), TestCaseError> {
+    let (original, parsed) = (seconds(original).abs(), seconds(parsed).abs());
+    let tolerance = f64::max(parsed / 2.0, f64::from(window.max(11)));
+    prop_assert!(
+        (original - parsed).abs() <= tolerance,
+        "`{}` is {} seconds, {} expected",
+        text,
+        parsed,
+        original
+    );
+    Ok(())
+}
+
+/// The sign of the parsed span follows the tense of the text
+fn assert_tense(tense: Tense, parsed: jiff::Span, text: &str) -> Result<(), TestCaseError> {
+    match tense {
+        Tense::Past => prop_assert!(!parsed.is_positive(), "`{}`", text),
+        Tense::Present | Tense::Future => prop_assert!(!parsed.is_negative(), "`{}`", text),
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn rough(span in prop_oneof![span(), rough_boundary()]) {
+        let ht = HumanTime::from(span);
+        for tense in [Tense::Past, Tense::Present, Tense::Future] {
+            let text = ht.to_text_en(Accuracy::Rough, tense)?;
+            let parsed = parse(&text)?;
+            assert_rough(span, parsed, &text, 11)?;
+            assert_tense(tense, parsed, &text)?;
+        }
+
+        let text = ht.to_string();
+        let parsed = parse(&text)?;
+        assert_rough(span, parsed, &text, 11)?;
+        prop_assert!(parsed.is_zero() || parsed.signum() == span.signum(), "`{}`", text);
+    }
+
+    #[test]
+    fn rough_with_options(span in prop_oneof![span(), rough_boundary()], (options, window) in options()) {
+        let ht = HumanTime::from(span);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?;
+        let parsed = parse(&text)?;
+        assert_rough(span, parsed, &text, window)?;
+        assert_tense(Tense::Past, parsed, &text)?;
+    }
+
+    #[test]
+    fn precise(span in span()) {
+        let ht = HumanTime::from(span);
+        for tense in [Tense::Past, Tense::Present, Tense::Future] {
+            let text = ht.to_text_en(Accuracy::Precise, tense)?;
+            let parsed = parse(&text)?;
+            prop_assert_eq!(Ordering::Equal, parsed.abs().compare(span.abs()).unwrap(), "`{}`", text);
+            assert_tense(tense, parsed, &text)?;
+        }
+
+        let text = format!("{:#}", ht);
+        let parsed = parse(&text)?;
+        prop_assert_eq!(Ordering::Equal, parsed.compare(span).unwrap(), "`{}`", text);
+    }
+
+    #[test]
+    fn precise_with_significant_digits(span in span(), digits in 1..=9u8) {
+        let ht = HumanTime::from(span);
+        let options = FormatOptions::new().significant_digits(digits);
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Past, options)?;
+        let parsed = parse(&text)?;
+        assert_tense(Tense::Past, parsed, &text)?;
+        let (original, parsed) = (seconds(span).abs(), seconds(parsed).abs());
+        // Only the rest under a minute is rounded, to the significant digits, with some room
+        // for the precision of the totals of long spans
+        let tolerance = 30.0 * 10f64.powi(1 - i32::from(digits)) + 1e-5;
+        prop_assert!(
+            (original - parsed).abs() <= tolerance,
+            "`{}` is {} seconds, {} expected",
+            text,
+            parsed,
+            original
+        );
+    }
+
+    #[test]
+    fn compact(span in span()) {
+        let text = HumanTime::from(span).to_text_compact()?;
+        let parsed = jiffy::parse_compact(&text).unwrap();
+        prop_assert_eq!(Ordering::Equal, parsed.compare(span).unwrap(), "`{}`", text);
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod options {
+    use jiffy::{Accuracy, FormatOptions, Qualifier, Tense};
+
+    #[test]
+    fn enums() -> anyhow::Result<()> {
+        assert_eq!(r#""Rough""#, serde_json::to_string(&Accuracy::Rough)?);
+        assert_eq!(Accuracy::Precise, serde_json::from_str(r#""Precise""#)?);
+        assert_eq!(r#""Future""#, serde_json::to_string(&Tense::Future)?);
+        assert_eq!(Tense::Past, serde_json::from_str(r#""Past""#)?);
+        assert_eq!(r#""Almost""#, serde_json::to_string(&Qualifier::Almost)?);
+        Ok(())
+    }
+
+    #[test]
+    fn format_options() -> anyhow::Result<()> {
+        let options = FormatOptions::new().idioms(true);
+        let json = serde_json::to_string(&options)?;
+        assert_eq!(options, serde_json::from_str(&json)?);
+        Ok(())
+    }
+
+    #[test]
+    fn format_options_defaults() -> anyhow::Result<()> {
+        let options: FormatOptions = serde_json::from_str(r#"{"qualifiers":true}"#)?;
+        assert_eq!(FormatOptions::new().qualifiers(true), options);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod human_time {
+    use jiff::ToSpan;
+    use jiffy::HumanTime;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Event {
+        span: HumanTime,
+        #[serde(with = "jiffy::serde::span")]
+        with_span: HumanTime,
+        #[serde(with = "jiffy::serde::text")]
+        text: HumanTime,
+    }
+
+    #[test]
+    fn span() -> anyhow::Result<()> {
+        let ht = HumanTime::from(1.days().hours(2));
+        assert_eq!(r#""P1DT2H""#, serde_json::to_string(&ht)?);
+        assert_eq!(ht, serde_json::from_str(r#""P1DT2H""#)?);
+        Ok(())
+    }
+
+    #[test]
+    fn helpers() -> anyhow::Result<()> {
+        let event = Event {
+            span: HumanTime::from(15.minutes()),
+            with_span: HumanTime::from((-3).hours()),
+            text: HumanTime::from((-400).days().hours(5)),
+        };
+        let json = serde_json::to_string(&event)?;
+        assert_eq!(
+            r#"{"span":"PT15M","with_span":"-PT3H","text":"1 year, 1 month, 5 days and 5 hours ago"}"#,
+            json
+        );
+        assert_eq!(event, serde_json::from_str(&json)?);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_text() {
+        let json = r#"{"span":"PT15M","with_span":"PT3H","text":"in 2 fortnights"}"#;
+        let err = serde_json::from_str::<Event>(json).unwrap_err();
+        assert_eq!(
+            "expected a unit, found `fortnights` at position 5 at line 1 column 60",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+macro_rules! significant_test  {
+    ($($name:ident: $duration:expr, $accuracy:expr, $digits:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from($duration);
+            let options = FormatOptions::new().significant_digits($digits).now_window(0);
+            let text = ht.to_text_en_with($accuracy, Tense::Present, options)?;
+            assert_eq!($text, text);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod precise {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    // test_name: Duration, Accuracy, Digits, "Text"
+    significant_test! {
+        seconds: 1.second().milliseconds(250).microseconds(3).nanoseconds(12),
+            Accuracy::Precise, 3, "1.25 seconds",
+        millis: 250.milliseconds().nanoseconds(12), Accuracy::Precise, 3, "250 ms",
+        millis_fraction: 1.millisecond().microseconds(500), Accuracy::Precise, 3, "1.5 ms",
+        micros: 12.microseconds().nanoseconds(345), Accuracy::Precise, 3, "12.3 µs",
+        nanos: 12.nanoseconds(), Accuracy::Precise, 3, "12 ns",
+        one_digit: 1.second().milliseconds(250), Accuracy::Precise, 1, "1 second",
+        more_digits: 1.second().milliseconds(250).microseconds(3), Accuracy::Precise, 7,
+            "1.250003 seconds",
+        round_up_unit: 999.milliseconds().microseconds(700), Accuracy::Precise, 3, "1 second",
+        with_minutes: 2.minutes().seconds(5).milliseconds(500), Accuracy::Precise, 2,
+            "2 minutes and 5.5 seconds",
+        whole_minutes: 2.minutes(), Accuracy::Precise, 3, "2 minutes",
+        carry_minute: 1.minute().seconds(59).milliseconds(700), Accuracy::Precise, 1,
+            "2 minutes",
+        carry_minute_digits: 1.minute().seconds(59).milliseconds(999), Accuracy::Precise, 3,
+            "2 minutes",
+        carry_hour: 1.hour().minutes(59).seconds(59).milliseconds(900), Accuracy::Precise, 2,
+            "2 hours",
+        carry_day: 1.day().hours(23).minutes(59).seconds(59).milliseconds(900),
+            Accuracy::Precise, 2, "2 days",
+        carry_negative: (-59).seconds().milliseconds(-700), Accuracy::Precise, 1, "1 minute",
+        empty: 0.seconds(), Accuracy::Precise, 3, "0 seconds",
+        negative: (-250).milliseconds(), Accuracy::Precise, 3, "250 ms",
+    }
+}
+
+#[cfg(test)]
+mod rough {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    // test_name: Duration, Accuracy, Digits, "Text"
+    significant_test! {
+        seconds: 12.seconds().milliseconds(345), Accuracy::Rough, 3, "12.3 seconds",
+        millis: 250.milliseconds().nanoseconds(12), Accuracy::Rough, 3, "250 ms",
+        micros: 1.microsecond().nanoseconds(500), Accuracy::Rough, 2, "1.5 µs",
+        minute: 50.seconds().milliseconds(500), Accuracy::Rough, 3, "a minute",
+        rounded_minute: 45.seconds().milliseconds(900), Accuracy::Rough, 1, "a minute",
+        under_minute: 45.seconds().milliseconds(400), Accuracy::Rough, 3, "45.4 seconds",
+    }
+}
+
+#[cfg(test)]
+mod options {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    #[test]
+    fn off_by_default() -> anyhow::Result<()> {
+        let ht = HumanTime::from(1.second().milliseconds(250).microseconds(3));
+        assert_eq!(
+            "1 second, 250 ms and 3 µs",
+            ht.to_text_en(Accuracy::Precise, Tense::Present)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn now_window() -> anyhow::Result<()> {
+        let ht = HumanTime::from((-250).milliseconds());
+        let options = FormatOptions::new().significant_digits(3);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+        assert_eq!("now", text);
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Past, options)?;
+        assert_eq!("250 ms ago", text);
+        Ok(())
+    }
+
+    #[test]
+    fn components() -> anyhow::Result<()> {
+        let ht = HumanTime::from(1.second().milliseconds(250));
+        let options = FormatOptions::new().significant_digits(3);
+        let components = ht
+            .components(Accuracy::Precise, Tense::Present, options)?
+            .map(|c| (c.unit(), c.value()))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(Some(jiff::Unit::Second), 1.25)], components);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_digits() {
+        let ht = HumanTime::from(250.milliseconds());
+        let options = FormatOptions::new().significant_digits(0);
+        let text = ht.to_text_en_with(Accuracy::Precise, Tense::Present, options);
+        assert!(text.is_err());
+    }
+
+    #[test]
+    fn parse() -> anyhow::Result<()> {
+        let ht = HumanTime::from(1.second().milliseconds(250));
+        assert_eq!(ht, "1.25 seconds".parse()?);
+        assert_eq!(
+            HumanTime::from((-2).minutes().seconds(-5).milliseconds(-500)),
+            "2 minutes and 5.5 seconds ago".parse()?
+        );
+        assert_eq!(
+            HumanTime::from(1.millisecond().microseconds(500)),
+            "in 1.5 ms".parse()?
+        );
+        assert_eq!(
+            HumanTime::from(12.microseconds().nanoseconds(300)),
+            "12.3 µs".parse()?
+        );
+        assert_eq!(HumanTime::from(1.hour().minutes(30)), "1.5 hours".parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!("1.5 days".parse::<HumanTime>().is_err());
+        assert!("1. seconds".parse::<HumanTime>().is_err());
+        assert!(".5 seconds".parse::<HumanTime>().is_err());
+        assert!("1.2.3 seconds".parse::<HumanTime>().is_err());
+    }
+}
@@ -0,0 +1,129 @@
+macro_rules! units_test  {
+    ($($name:ident: $days:expr, $rough:expr, $precise:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let ht = HumanTime::from(jiff::Span::new().days($days));
+            let options = FormatOptions::new().quarters(true).decades(true).centuries(true);
+            assert_eq!($rough, ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?);
+            assert_eq!($precise, ht.to_text_en_with(Accuracy::Precise, Tense::Future, options)?);
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod all_units {
+    use jiffy::{Accuracy, FormatOptions, HumanTime, Tense};
+
+    // test_name: Days, "Rough text", "Precise text"
+    units_test! {
+        month: 60, "2 months ago", "in 2 months",
+        quarter: 91, "a quarter ago", "in 1 quarter",
+        quarters: 200, "2 quarters ago", "in 2 quarters and 18 days",
+        three_quarters: 300, "3 quarters ago", "in 3 quarters and 27 days",
+        year: 400, "a year ago", "in 1 year, 1 month and 5 days",
+        years: 3000, "8 years ago", "in 8 years, 2 months and 20 days",
+        decade: 3650, "a decade ago", "in 1 decade",
+        decades: 7300, "2 decades ago", "in 2 decades",
+        decades_and_years: 10_000, "2 decades ago", "in 2 decades, 7 years, 1 quarter, 1 month and 24 days",
+        century: 36_500, "a century ago", "in 1 century",
+        centuries: 110_000, "3 centuries ago", "in 3 centuries, 1 year, 1 quarter, 1 month and 14 days",
+    }
+}
+
+#[cfg(test)]
+mod opt_in {
+    use jiff::ToSpan;
+    use jiffy::{Accuracy, ComponentKind, FormatOptions, HumanTime, Tense};
+
+    #[test]
+    fn just_over_boundary() -> anyhow::Result<()> {
+        let ht = HumanTime::from(4_730_400_001i64.seconds());
+        let options = FormatOptions::new().centuries(true);
+        assert_eq!(
+            "2 centuries ago",
+            ht.to_text_en_with(Accuracy::Rough, Tense::Past, options)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn off_by_default() -> anyhow::Result<()> {
+        let ht = HumanTime::from(7300.days());
+        assert_eq!("20 years", ht.to_text_en(Accuracy::Rough, Tense::Present)?);
+        assert_eq!(
+            "20 years",
+            ht.to_text_en(Accuracy::Precise, Tense::Present)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn decades_without_centuries() -> anyhow::Result<()> {
+        let ht = HumanTime::from(73_000.days());
+        let options = FormatOptions::new().decades(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+        assert_eq!("20 decades", text);
+        Ok(())
+    }
+
+    #[test]
+    fn qualifiers() -> anyhow::Result<()> {
+        let ht = HumanTime::from(4000.days());
+        let options = FormatOptions::new().decades(true).qualifiers(true);
+        let text = ht.to_text_en_with(Accuracy::Rough, Tense::Present, options)?;
+        assert_eq!("about a decade", text);
+        Ok(())
+    }
+
+    #[test]
+    fn components() -> anyhow::Result<()> {
+        let ht = HumanTime::from(40165.days().hours(1));
+        let options = FormatOptions::new().decades(true).centuries(true);
+        let components = ht
+            .components(Accuracy::Precise, Tense::Present, options)?
+            .map(|c| (c.kind(), c.value()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (ComponentKind::Century, 1.0),
+                (ComponentKind::Decade, 1.0),
+                (ComponentKind::Unit(jiff::Unit::Day), 15.0),
+                (ComponentKind::Unit(jiff::Unit::Hour), 1.0),
+            ],
+            components
+        );
+
+        let ht = HumanTime::from(182.days());
+        let options = FormatOptions::new().quarters(true);
+        let kinds = ht
+            .components(Accuracy::Rough, Tense::Present, options)?
+            .map(|c| c.kind())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![ComponentKind::Quarter], kinds);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod parse {
+    use jiff::ToSpan;
+    use jiffy::HumanTime;
+
+    #[test]
+    fn units() -> anyhow::Result<()> {
+        assert_eq!(HumanTime::from((-182).days()), "2 quarters ago".parse()?);
+        assert_eq!(HumanTime::from(91.days()), "in a quarter".parse()?);
+        assert_eq!(HumanTime::from(3650.days()), "in a decade".parse()?);
+        assert_eq!(
+            HumanTime::from((-109_500).days()),
+            "3 centuries ago".parse()?
+        );
+        assert_eq!(HumanTime::from(36_865.days()), "1 century, 1 year".parse()?);
+        assert_eq!(
+            HumanTime::from(15.minutes()),
+            "a quarter of an hour".parse()?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+macro_rules! zoned_test  {
+    ($($name:ident: $reference:expr, $datetime:expr, $rough:expr, $precise:expr,)+) => {
+        $(#[test]
+        fn $name() -> anyhow::Result<()> {
+            let reference = $reference.in_tz("America/New_York")?;
+            let dt = $datetime.in_tz("America/New_York")?;
+            let ht = HumanTime::from_zoned(&dt, &reference)?;
+            assert_eq!($rough, format!("{}", ht));
+            assert_eq!($precise, format!("{:#}", ht));
+            Ok(())
+        })+
+    }
+}
+
+#[cfg(test)]
+mod dst {
+    use jiff::civil::date;
+    use jiffy::HumanTime;
+
+    // Clocks in New York move forward on 2026-03-08 and back on 2026-11-01
+    // test_name: Reference, Date and time, "Rough text", "Precise text"
+    zoned_test! {
+        short_day: date(2026, 3, 7).at(9, 0, 0, 0), date(2026, 3, 8).at(9, 0, 0, 0),
+            "in a day", "in 1 day",
+        long_day: date(2026, 10, 31).at(9, 0, 0, 0), date(2026, 11, 1).at(9, 0, 0, 0),
+            "in a day", "in 1 day",
+        short_day_ago: date(2026, 3, 8).at(9, 0, 0, 0), date(2026, 3, 7).at(9, 0, 0, 0),
+            "a day ago", "1 day ago",
+        week: date(2026, 3, 4).at(9, 0, 0, 0), date(2026, 3, 11).at(9, 0, 0, 0),
+            "in a week", "in 7 days",
+        days_and_hours: date(2026, 10, 31).at(9, 0, 0, 0), date(2026, 11, 2).at(12, 0, 0, 0),
+            "in 2 days", "in 2 days and 3 hours",
+        same_day: date(2026, 3, 8).at(0, 0, 0, 0), date(2026, 3, 8).at(12, 0, 0, 0),
+            "in 11 hours", "in 11 hours",
+    }
+}
+
+#[cfg(test)]
+mod zones {
+    use jiff::civil::date;
+    use jiffy::HumanTime;
+
+    #[test]
+    fn days_in_reference_zone() -> anyhow::Result<()> {
+        let reference = date(2026, 3, 7).at(9, 0, 0, 0).in_tz("America/New_York")?;
+        let dt = date(2026, 3, 8).at(13, 0, 0, 0).in_tz("UTC")?;
+        let ht = HumanTime::from_zoned(&dt, &reference)?;
+        assert_eq!("in 1 day", format!("{:#}", ht));
+        Ok(())
+    }
+}